@@ -4,16 +4,20 @@ use quote::format_ident;
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
+    punctuated::Punctuated,
     spanned::Spanned,
     Arm, Attribute, Block, Data, DataEnum, DataStruct, DeriveInput, Error, Expr, ExprLit, Fields,
-    FieldsNamed, FieldsUnnamed, Ident, Index, ItemImpl, Lit, Meta, Path, Result, Stmt,
+    FieldsNamed, FieldsUnnamed, Ident, Index, ItemConst, Lit, LitStr, Meta, Path, Result, Stmt,
+    Token, WherePredicate,
 };
 
-pub(crate) fn get_is_plutus_data_instance(input: DeriveInput) -> Result<ItemImpl> {
+pub(crate) fn get_is_plutus_data_instance(input: DeriveInput) -> Result<ItemConst> {
     let type_name = &input.ident;
 
     let strategy = get_derive_strategy(&input)?;
 
+    let ContainerAttributes { crate_path, bound } = get_container_attributes(&input)?;
+
     let plutus_data_input_var: Ident = parse_quote!(plutus_data);
 
     let (encoder, decoder) = match strategy {
@@ -24,24 +28,38 @@ pub(crate) fn get_is_plutus_data_instance(input: DeriveInput) -> Result<ItemImpl
 
     let mut generics = input.generics;
 
-    // TODO(chfanghr): Do we care about type role? Can we determine it?
-    generics.type_params_mut().for_each(|param| {
-        param.bounds.push(parse_quote!(plutus_data::IsPlutusData));
-    });
+    match bound {
+        Some(predicates) => generics.make_where_clause().predicates.extend(predicates),
+        // TODO(chfanghr): Do we care about type role? Can we determine it?
+        None => generics.type_params_mut().for_each(|param| {
+            param
+                .bounds
+                .push(parse_quote!(plutus_ledger_api::plutus_data::IsPlutusData));
+        }),
+    }
 
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    Ok(parse_quote!(
-        impl #impl_generics plutus_ledger_api::plutus_data::IsPlutusData for #type_name #type_generics #where_clause {
-            fn to_plutus_data(&self) -> plutus_ledger_api::plutus_data::PlutusData {
-                #encoder
-            }
+    // The generated code refers to the library as `plutus_ledger_api`. If the user gave us another
+    // path, we alias it inside an anonymous const block, so the alias doesn't leak into their scope.
+    let crate_alias: Option<Stmt> =
+        crate_path.map(|crate_path| parse_quote!(use #crate_path as plutus_ledger_api;));
 
-            fn from_plutus_data(plutus_data: &plutus_ledger_api::plutus_data::PlutusData) -> Result<Self, plutus_ledger_api::plutus_data::PlutusDataError>
-                where Self: Sized {
-                #decoder
+    Ok(parse_quote!(
+        const _: () = {
+            #crate_alias
+
+            impl #impl_generics plutus_ledger_api::plutus_data::IsPlutusData for #type_name #type_generics #where_clause {
+                fn to_plutus_data(&self) -> plutus_ledger_api::plutus_data::PlutusData {
+                    #encoder
+                }
+
+                fn from_plutus_data(plutus_data: &plutus_ledger_api::plutus_data::PlutusData) -> Result<Self, plutus_ledger_api::plutus_data::PlutusDataError>
+                    where Self: Sized {
+                    #decoder
+                }
             }
-        }
+        };
     ))
 }
 
@@ -133,6 +151,68 @@ fn get_derive_strategy(input: &DeriveInput) -> Result<DeriveStrategy> {
     }
 }
 
+/// Container attributes given as `#[plutus_data(crate = "...", bound = "...")]`
+#[derive(Debug, Default)]
+struct ContainerAttributes {
+    /// Path to the `plutus_ledger_api` crate, in case it was renamed or re-exported
+    crate_path: Option<Path>,
+    /// Where predicates replacing the default `IsPlutusData` bounds on type parameters
+    bound: Option<Punctuated<WherePredicate, Token![,]>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ContainerAttributeError {
+    #[error("Unknown plutus_data attribute {0}. Should be one of crate and bound.")]
+    UnknownAttribute(String),
+    #[error("Attribute {0} specified more than once.")]
+    MoreThanOneSpecified(String),
+}
+
+fn get_container_attributes(input: &DeriveInput) -> Result<ContainerAttributes> {
+    let mut attributes = ContainerAttributes::default();
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("plutus_data"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                if attributes.crate_path.is_some() {
+                    Err(meta.error(ContainerAttributeError::MoreThanOneSpecified(
+                        "crate".into(),
+                    )))?
+                }
+
+                let path: LitStr = meta.value()?.parse()?;
+                attributes.crate_path = Some(path.parse()?);
+
+                Ok(())
+            } else if meta.path.is_ident("bound") {
+                if attributes.bound.is_some() {
+                    Err(meta.error(ContainerAttributeError::MoreThanOneSpecified(
+                        "bound".into(),
+                    )))?
+                }
+
+                let predicates: LitStr = meta.value()?.parse()?;
+                attributes.bound = Some(predicates.parse_with(Punctuated::parse_terminated)?);
+
+                Ok(())
+            } else {
+                let name = meta
+                    .path
+                    .get_ident()
+                    .map_or_else(|| "<path>".into(), Ident::to_string);
+
+                Err(meta.error(ContainerAttributeError::UnknownAttribute(name)))
+            }
+        })?;
+    }
+
+    Ok(attributes)
+}
+
 #[derive(Debug, thiserror::Error)]
 enum NewtypeStrategyError {
     #[error("Only struct types are supported by newtype strategy")]
@@ -160,9 +240,11 @@ fn get_newtype_encoder_decoder(input: &DeriveInput) -> Result<(Block, Block)> {
     let field = s.fields.iter().next().unwrap();
 
     let encoder = match &field.ident {
-        None => parse_quote!({ self.0.to_plutus_data() }),
+        None => {
+            parse_quote!({ plutus_ledger_api::plutus_data::IsPlutusData::to_plutus_data(&self.0) })
+        }
         Some(ident) => parse_quote!({
-            self.#ident.to_plutus_data()
+            plutus_ledger_api::plutus_data::IsPlutusData::to_plutus_data(&self.#ident)
         }),
     };
 
@@ -443,7 +525,7 @@ fn struct_with_named_fields_to_list_of_plutus_data(fields: &FieldsNamed) -> Bloc
         .map(|field| -> Expr {
             let field_name = field.ident.as_ref().unwrap();
 
-            parse_quote!(self.#field_name)
+            parse_quote!(&self.#field_name)
         })
         .collect::<Vec<_>>();
 
@@ -515,7 +597,7 @@ fn struct_with_unnamed_fields_to_list_of_plutus_data(fields: &FieldsUnnamed) ->
         .map(|idx| -> Expr {
             let idx: Index = idx.into();
 
-            parse_quote!(self.#idx)
+            parse_quote!(&self.#idx)
         })
         .collect::<Vec<_>>();
 
@@ -581,7 +663,10 @@ fn struct_with_unnamed_fields_from_plutus_data_constr(
 }
 
 fn struct_with_no_field_to_plutus_data_list() -> Block {
-    parse_quote!(plutus_data::PlutusData::Constr(0.into(), vec![]))
+    parse_quote!(plutus_ledger_api::plutus_data::PlutusData::Constr(
+        0.into(),
+        vec![]
+    ))
 }
 
 fn struct_with_no_field_from_plutus_data_list(plutus_data_input_var: &Ident) -> Block {
@@ -598,7 +683,10 @@ fn struct_with_no_field_from_plutus_data_list(plutus_data_input_var: &Ident) ->
 }
 
 fn struct_with_no_field_to_plutus_data_constr() -> Block {
-    parse_quote!(plutus_data::PlutusData::Constr(0.into(), vec![]))
+    parse_quote!(plutus_ledger_api::plutus_data::PlutusData::Constr(
+        0.into(),
+        vec![]
+    ))
 }
 
 fn struct_with_no_field_from_plutus_data_constr(plutus_data_input_var: &Ident) -> Block {
@@ -615,9 +703,9 @@ fn struct_with_no_field_from_plutus_data_constr(plutus_data_input_var: &Ident) -
 }
 
 fn data_fields_to_list_of_plutus_data(field_accessors: &[Expr]) -> Block {
-    let fields_to_plutus_data = field_accessors
-        .iter()
-        .map(|a| -> Expr { parse_quote!(#a.to_plutus_data()) });
+    let fields_to_plutus_data = field_accessors.iter().map(|a| -> Expr {
+        parse_quote!(plutus_ledger_api::plutus_data::IsPlutusData::to_plutus_data(#a))
+    });
 
     parse_quote!({ vec![ #(#fields_to_plutus_data),* ] })
 }
@@ -673,7 +761,7 @@ fn data_with_unnamed_fields_from_list_of_plutus_data(
         .zip(parsed_field_idents.clone())
         .map(|(unparsed, parsed)| -> Stmt {
             parse_quote!(
-                let #parsed = IsPlutusData::from_plutus_data(#unparsed)?;
+                let #parsed = plutus_ledger_api::plutus_data::IsPlutusData::from_plutus_data(#unparsed)?;
             )
         });
 
@@ -3,7 +3,17 @@ use syn::{parse_macro_input, DeriveInput};
 
 pub(crate) mod derive_impl;
 
-#[proc_macro_derive(IsPlutusData, attributes(is_plutus_data_derive_strategy))]
+/// Derive an `IsPlutusData` instance
+///
+/// The encoding is selected with `#[is_plutus_data_derive_strategy = "Constr" | "List" | "Newtype"]`
+/// (defaults to `Constr`).
+///
+/// Container attributes:
+/// - `#[plutus_data(crate = "path::to::plutus_ledger_api")]`: path to the library, in case it is
+///   renamed in Cargo.toml or re-exported from another crate
+/// - `#[plutus_data(bound = "T: IsPlutusData")]`: where predicates replacing the default
+///   `IsPlutusData` bound on every type parameter (use `bound = ""` for no bounds at all)
+#[proc_macro_derive(IsPlutusData, attributes(is_plutus_data_derive_strategy, plutus_data))]
 pub fn derive_is_plutus_data(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive_impl::get_is_plutus_data_instance(input)
@@ -0,0 +1,386 @@
+//! Human readable formatting and parsing of values, using asset metadata such as tickers and
+//! decimals
+//!
+//! Metadata can be registered manually, or loaded from
+//! [CIP-26](https://cips.cardano.org/cip/CIP-0026) off-chain metadata JSON files (requires the
+//! `serde` feature).
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+#[cfg(feature = "serde")]
+use data_encoding::HEXLOWER;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConversionError;
+use crate::v1::value::{AssetClass, CurrencySymbol, TokenName, Value};
+
+/// Metadata of an asset, used to display its quantities
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AssetMetadata {
+    pub ticker: Option<String>,
+    pub name: Option<String>,
+    /// Number of decimal places of a display unit
+    pub decimals: u32,
+}
+
+///////////////////
+// AssetRegistry //
+///////////////////
+
+/// Metadata of known assets
+///
+/// Quantities of assets without a ticker are displayed with their asset class, token names are
+/// decoded as UTF-8 where possible, or prefixed by `0x` otherwise (e.g. `1 <policy>.MyNFT`).
+/// Quantities of unregistered assets, and Ada without a ticker (`lovelace`), are displayed without
+/// decimals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetRegistry(pub BTreeMap<AssetClass, AssetMetadata>);
+
+impl AssetRegistry {
+    /// Registry holding only Ada, with the `ADA` ticker and 6 decimals
+    pub fn new() -> Self {
+        let mut registry = AssetRegistry(BTreeMap::new());
+
+        registry.insert(
+            ada_asset_class(),
+            AssetMetadata {
+                ticker: Some(String::from("ADA")),
+                name: Some(String::from("Cardano")),
+                decimals: 6,
+            },
+        );
+
+        registry
+    }
+
+    /// Register the metadata of an asset, returning the previous metadata if there was one
+    pub fn insert(
+        &mut self,
+        asset_class: AssetClass,
+        metadata: AssetMetadata,
+    ) -> Option<AssetMetadata> {
+        self.0.insert(asset_class, metadata)
+    }
+
+    pub fn get(&self, asset_class: &AssetClass) -> Option<&AssetMetadata> {
+        self.0.get(asset_class)
+    }
+
+    /// Find the first asset registered with the ticker
+    pub fn find_ticker(&self, ticker: &str) -> Option<(&AssetClass, &AssetMetadata)> {
+        self.0
+            .iter()
+            .find(|(_, metadata)| metadata.ticker.as_deref() == Some(ticker))
+    }
+
+    /// Format a quantity of an asset (e.g. `3.000001 HOSKY`)
+    pub fn format_amount(&self, asset_class: &AssetClass, amount: &BigInt) -> String {
+        match self.get(asset_class) {
+            Some(AssetMetadata {
+                ticker: Some(ticker),
+                decimals,
+                ..
+            }) => format!("{} {}", format_decimal(amount, *decimals), ticker),
+            Some(AssetMetadata { decimals, .. }) if !asset_class.currency_symbol.is_ada() => {
+                format!(
+                    "{} {}",
+                    format_decimal(amount, *decimals),
+                    format_unit(asset_class)
+                )
+            }
+            _ => format!("{} {}", amount, format_unit(asset_class)),
+        }
+    }
+
+    /// Format a value, joining its entries with ` + ` (e.g. `12.5 ADA + 3.000001 HOSKY`)
+    pub fn format_value(&self, value: &Value) -> String {
+        let entries = value
+            .flatten()
+            .into_iter()
+            .map(|(currency_symbol, token_name, amount)| {
+                self.format_amount(
+                    &AssetClass {
+                        currency_symbol: currency_symbol.clone(),
+                        token_name: token_name.clone(),
+                    },
+                    amount,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            self.format_amount(&ada_asset_class(), &BigInt::zero())
+        } else {
+            entries.join(" + ")
+        }
+    }
+
+    /// Parse a quantity of an asset, given as a decimal number and a unit divided by a space
+    ///
+    /// The unit is either a registered ticker, `lovelace`, or an asset class with its token name
+    /// in UTF-8, or in hexadecimal prefixed by `0x` (e.g. `1 <policy>.MyNFT`)
+    pub fn parse_amount(&self, s: &str) -> Result<(AssetClass, BigInt), ConversionError> {
+        let parse_error = |err: anyhow::Error| {
+            ConversionError::ParseError(anyhow!("Error while parsing amount '{}': {}", s, err))
+        };
+
+        let (quantity, unit) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| parse_error(anyhow!("expected a quantity and a unit")))?;
+
+        let (asset_class, decimals) = match (self.find_ticker(unit.trim()), unit.trim()) {
+            (Some((asset_class, metadata)), _) => (asset_class.clone(), metadata.decimals),
+            (None, "lovelace") => (ada_asset_class(), 0),
+            (None, unit) => {
+                let asset_class = parse_unit(unit).map_err(|err| parse_error(err.into()))?;
+                let decimals = self
+                    .get(&asset_class)
+                    .map_or(0, |metadata| metadata.decimals);
+
+                (asset_class, decimals)
+            }
+        };
+
+        let amount = parse_decimal(quantity, decimals).map_err(parse_error)?;
+
+        Ok((asset_class, amount))
+    }
+
+    /// Parse a value, given as amounts joined with `+` (e.g. `12.5 ADA + 3.000001 HOSKY`)
+    pub fn parse_value(&self, s: &str) -> Result<Value, ConversionError> {
+        s.split('+').try_fold(Value::new(), |mut value, amount| {
+            let (asset_class, amount) = self.parse_amount(amount)?;
+
+            value.add_token_mut(
+                &asset_class.currency_symbol,
+                &asset_class.token_name,
+                &amount,
+            );
+
+            Ok(value)
+        })
+    }
+}
+
+impl Default for AssetRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn ada_asset_class() -> AssetClass {
+    AssetClass {
+        currency_symbol: CurrencySymbol::Ada,
+        token_name: TokenName::ada(),
+    }
+}
+
+/// Format an asset class without a ticker as `lovelace` or `<policy>.<token name>`
+///
+/// Token names are written in UTF-8 only if they are plain identifiers, otherwise they are
+/// written in hexadecimal prefixed by `0x`, so names containing separators (`+`, whitespace) or
+/// starting with `0x` can be parsed back.
+fn format_unit(asset_class: &AssetClass) -> String {
+    let token_name = &asset_class.token_name;
+
+    if token_name.is_empty() {
+        return format!("{:#}", asset_class.currency_symbol);
+    }
+
+    match std::str::from_utf8(&token_name.0 .0) {
+        Ok(name) if is_plain_identifier(name) => {
+            format!("{:#}.{}", asset_class.currency_symbol, name)
+        }
+        _ => format!("{:#}.0x{}", asset_class.currency_symbol, token_name),
+    }
+}
+
+fn is_plain_identifier(name: &str) -> bool {
+    !name.starts_with("0x")
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Parse the unit of a native token asset class (see `format_unit`)
+fn parse_unit(unit: &str) -> Result<AssetClass, ConversionError> {
+    let (currency_symbol, token_name) = unit.split_once('.').unwrap_or((unit, ""));
+
+    let token_name = match token_name.strip_prefix("0x") {
+        Some(hex) => TokenName::from_str(hex)?,
+        None => TokenName::from_string(token_name)?,
+    };
+
+    Ok(AssetClass {
+        currency_symbol: CurrencySymbol::from_str(currency_symbol)?,
+        token_name,
+    })
+}
+
+/// Format an integer quantity as a decimal number, omitting trailing zeros
+fn format_decimal(amount: &BigInt, decimals: u32) -> String {
+    let unit = BigInt::from(10).pow(decimals);
+    let sign = if amount.is_negative() { "-" } else { "" };
+    let whole = amount.abs() / &unit;
+    let fraction = amount.abs() % &unit;
+
+    if fraction.is_zero() {
+        format!("{}{}", sign, whole)
+    } else {
+        let fraction = format!("{:0>width$}", fraction, width = decimals as usize);
+
+        format!("{}{}.{}", sign, whole, fraction.trim_end_matches('0'))
+    }
+}
+
+/// Parse a decimal number into an integer quantity, multiplying it by 10^decimals
+fn parse_decimal(s: &str, decimals: u32) -> Result<BigInt, anyhow::Error> {
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", s),
+    };
+
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    if whole.is_empty() && fraction.is_empty()
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(anyhow!("invalid decimal number '{}'", s));
+    }
+
+    if fraction.len() > decimals as usize {
+        return Err(anyhow!("'{}' has more than {} decimal places", s, decimals));
+    }
+
+    let digits = format!(
+        "{}{}{:0<width$}",
+        sign,
+        whole,
+        fraction,
+        width = decimals as usize
+    );
+
+    Ok(BigInt::from_str(&digits)?)
+}
+
+////////////
+// CIP-26 //
+////////////
+
+#[cfg(feature = "serde")]
+#[derive(thiserror::Error, Debug)]
+pub enum AssetRegistryError {
+    #[error("Couldn't read CIP-26 metadata file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Couldn't decode CIP-26 metadata JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid CIP-26 subject '{subject}': {source}")]
+    InvalidSubject {
+        subject: String,
+        source: ConversionError,
+    },
+}
+
+/// Subset of the CIP-26 metadata properties used by the registry
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Cip26Metadata {
+    subject: String,
+    name: Option<Cip26Property<String>>,
+    ticker: Option<Cip26Property<String>>,
+    decimals: Option<Cip26Property<u32>>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Cip26Property<T> {
+    value: T,
+}
+
+#[cfg(feature = "serde")]
+impl AssetRegistry {
+    /// Register an asset from the JSON content of a CIP-26 metadata file, returning its asset class
+    pub fn insert_cip26_json(&mut self, json: &str) -> Result<AssetClass, AssetRegistryError> {
+        let metadata: Cip26Metadata = serde_json::from_str(json)?;
+
+        let asset_class = parse_subject(&metadata.subject).map_err(|source| {
+            AssetRegistryError::InvalidSubject {
+                subject: metadata.subject.clone(),
+                source,
+            }
+        })?;
+
+        self.insert(
+            asset_class.clone(),
+            AssetMetadata {
+                ticker: metadata.ticker.map(|property| property.value),
+                name: metadata.name.map(|property| property.value),
+                decimals: metadata.decimals.map_or(0, |property| property.value),
+            },
+        );
+
+        Ok(asset_class)
+    }
+
+    /// Register an asset from a CIP-26 metadata file
+    pub fn load_cip26_file(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<AssetClass, AssetRegistryError> {
+        self.insert_cip26_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Register all assets from the `.json` files of a directory (e.g. the `mappings` directory
+    /// of a token registry)
+    pub fn load_cip26_dir(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Vec<AssetClass>, AssetRegistryError> {
+        let mut paths = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        paths.sort();
+
+        paths
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .map(|path| self.load_cip26_file(path))
+            .collect()
+    }
+}
+
+/// Decode a CIP-26 subject: a hex encoded policy id followed by the token name
+#[cfg(feature = "serde")]
+fn parse_subject(subject: &str) -> Result<AssetClass, ConversionError> {
+    let bytes = HEXLOWER
+        .decode(subject.to_ascii_lowercase().as_bytes())
+        .map_err(|err| ConversionError::hex_decode_error(err, subject))?;
+
+    if bytes.len() < 28 {
+        return Err(ConversionError::invalid_bytestring_length(
+            "CIP-26 subject",
+            28,
+            "greater than or equal to",
+            &bytes,
+        ));
+    }
+
+    let (policy_id, token_name) = bytes.split_at(28);
+
+    Ok(AssetClass {
+        currency_symbol: CurrencySymbol::from_bytes(policy_id.to_vec())?,
+        token_name: TokenName::from_bytes(token_name.to_vec())?,
+    })
+}
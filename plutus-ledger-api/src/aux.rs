@@ -4,6 +4,8 @@ use std::{
     str::FromStr,
 };
 
+use anyhow::anyhow;
+use bech32::{FromBase32, ToBase32};
 use nom::{
     branch::alt,
     character::complete::{char, digit1},
@@ -39,7 +41,7 @@ pub fn union_btree_maps_with<K: Clone + Ord, V: Clone, F: Fn(V, V) -> V>(
     l: BTreeMap<K, V>,
     r: BTreeMap<K, V>,
 ) -> BTreeMap<K, V> {
-    r.into_iter().fold(l.clone(), |mut acc, (k, vr)| {
+    r.into_iter().fold(l, |mut acc, (k, vr)| {
         let v = if let Some((_, vl)) = acc.remove_entry(&k) {
             f(vl, vr)
         } else {
@@ -65,6 +67,30 @@ pub fn union_b_tree_maps_with<const N: usize, K: Clone + Ord, V: Clone, F: Fn(&V
     })
 }
 
+/// Encode a bytestring in bech32 with the given human readable prefix
+pub(crate) fn encode_bech32(prefix: &str, bytes: &[u8]) -> Result<String, bech32::Error> {
+    bech32::encode(prefix, bytes.to_base32())
+}
+
+/// Decode a bech32 string, verifying that it has the expected human readable prefix
+pub(crate) fn decode_bech32(ctx: &str, prefix: &str, s: &str) -> Result<Vec<u8>, ConversionError> {
+    let parse_error = |err: anyhow::Error| {
+        ConversionError::ParseError(anyhow!("Error while parsing {} '{}': {}", ctx, s, err))
+    };
+
+    let (hrp, data) = bech32::decode(s).map_err(|err| parse_error(err.into()))?;
+
+    if hrp != prefix {
+        return Err(parse_error(anyhow!(
+            "expected prefix '{}', got '{}'",
+            prefix,
+            hrp
+        )));
+    }
+
+    Vec::<u8>::from_base32(&data).map_err(|err| parse_error(err.into()))
+}
+
 /// Verify that a given bytestring has the expected length
 pub(crate) fn guard_bytes(
     ctx: &str,
@@ -0,0 +1,480 @@
+//! Byron era bootstrap addresses
+//!
+//! Bootstrap addresses have no Plutus representation, but outputs locked by them can still be
+//! found in the UTxO set. See `LedgerAddress` for an address type that covers both eras.
+use std::io::Cursor;
+use std::str::FromStr;
+
+use cardano_serialization_lib as csl;
+use cbor_event::{de::Deserializer, se::Serializer, Len, Type};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csl::csl_to_pla::{TryFromCSL, TryFromCSLError};
+use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError};
+use crate::v1::crypto::LedgerBytes;
+
+//////////////////
+// ByronAddress //
+//////////////////
+
+/// A Byron era bootstrap address
+///
+/// The address keeps its original CBOR encoding, so converting it back to bytes or base58 is
+/// lossless, even if its attributes contain entries that are not parsed here.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct ByronAddress {
+    bytes: LedgerBytes,
+    root: LedgerBytes,
+    attributes: ByronAddressAttributes,
+    address_type: ByronAddressType,
+}
+
+/// Attributes of a Byron address
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ByronAddressAttributes {
+    /// HD derivation path of legacy Daedalus wallets, encrypted with the wallet's key
+    pub derivation_path: Option<LedgerBytes>,
+    /// Protocol magic of the network, only present on testnet addresses
+    pub network_magic: Option<u32>,
+}
+
+/// Kind of spending data the address root commits to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ByronAddressType {
+    PubKey,
+    Script,
+    Redeem,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ByronAddressError {
+    #[error("Invalid base58 character {0:?}")]
+    InvalidBase58Character(char),
+
+    #[error("Unable to decode the CBOR of a Byron address: {0}")]
+    Cbor(#[from] cbor_event::Error),
+
+    #[error("Expected a CBOR array of length {expected}, but got {got:?}")]
+    InvalidArrayLength { expected: u64, got: Len },
+
+    #[error("Expected the address payload to be tagged with CBOR tag 24, but got tag {0}")]
+    InvalidTag(u64),
+
+    #[error("Invalid CRC32 checksum: the address has {stored:#010x}, but its payload has {computed:#010x}")]
+    InvalidChecksum { stored: u64, computed: u32 },
+
+    #[error("Byron address root must be {ROOT_LENGTH} bytes long, but got {0}")]
+    InvalidRootLength(usize),
+
+    #[error("Unknown Byron address type {0}")]
+    UnknownAddressType(u64),
+
+    #[error("Network magic {0} does not fit in 32 bits")]
+    InvalidNetworkMagic(u64),
+
+    #[error("Unexpected bytes after the end of the address")]
+    TrailingBytes,
+}
+
+/// Length of the address root, a Blake2b-224 hash
+const ROOT_LENGTH: usize = 28;
+
+/// CBOR tag of embedded CBOR data items
+const EMBEDDED_CBOR_TAG: u64 = 24;
+
+const DERIVATION_PATH_KEY: u64 = 1;
+const NETWORK_MAGIC_KEY: u64 = 2;
+
+impl ByronAddress {
+    /// Build an address from its parts
+    pub fn new(
+        root: LedgerBytes,
+        attributes: ByronAddressAttributes,
+        address_type: ByronAddressType,
+    ) -> Result<Self, ByronAddressError> {
+        if root.0.len() != ROOT_LENGTH {
+            return Err(ByronAddressError::InvalidRootLength(root.0.len()));
+        }
+
+        let mut payload = Serializer::new_vec();
+        payload.write_array(Len::Len(3))?.write_bytes(&root.0)?;
+        encode_attributes(&mut payload, &attributes)?;
+        payload.write_unsigned_integer(address_type.into())?;
+        let payload = payload.finalize();
+
+        let mut address = Serializer::new_vec();
+        address
+            .write_array(Len::Len(2))?
+            .write_tag(EMBEDDED_CBOR_TAG)?
+            .write_bytes(&payload)?
+            .write_unsigned_integer(crc32(&payload).into())?;
+
+        Ok(ByronAddress {
+            bytes: LedgerBytes(address.finalize()),
+            root,
+            attributes,
+            address_type,
+        })
+    }
+
+    /// Decode the CBOR encoding of an address, validating its checksum
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ByronAddressError> {
+        let mut raw = Deserializer::from(Cursor::new(bytes));
+
+        expect_array(&mut raw, 2)?;
+        let tag = raw.tag()?;
+        if tag != EMBEDDED_CBOR_TAG {
+            return Err(ByronAddressError::InvalidTag(tag));
+        }
+        let payload = raw.bytes()?;
+        let stored = raw.unsigned_integer()?;
+        expect_end(raw, bytes.len())?;
+
+        let computed = crc32(&payload);
+        if stored != u64::from(computed) {
+            return Err(ByronAddressError::InvalidChecksum { stored, computed });
+        }
+
+        let mut raw = Deserializer::from(Cursor::new(payload.as_slice()));
+
+        expect_array(&mut raw, 3)?;
+        let root = raw.bytes()?;
+        if root.len() != ROOT_LENGTH {
+            return Err(ByronAddressError::InvalidRootLength(root.len()));
+        }
+        let attributes = decode_attributes(&mut raw)?;
+        let address_type = ByronAddressType::try_from(raw.unsigned_integer()?)?;
+        expect_end(raw, payload.len())?;
+
+        Ok(ByronAddress {
+            bytes: LedgerBytes(bytes.to_vec()),
+            root: LedgerBytes(root),
+            attributes,
+            address_type,
+        })
+    }
+
+    /// Decode a base58 address
+    pub fn from_base58(s: &str) -> Result<Self, ByronAddressError> {
+        Self::from_bytes(&decode_base58(s)?)
+    }
+
+    /// CBOR encoding of the address, as it appears in transaction outputs
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.0.clone()
+    }
+
+    pub fn to_base58(&self) -> String {
+        encode_base58(&self.bytes.0)
+    }
+
+    /// Hash of the address type, spending data and attributes
+    pub fn root(&self) -> &LedgerBytes {
+        &self.root
+    }
+
+    pub fn attributes(&self) -> &ByronAddressAttributes {
+        &self.attributes
+    }
+
+    pub fn address_type(&self) -> ByronAddressType {
+        self.address_type
+    }
+
+    /// Protocol magic of the network, or `None` for mainnet addresses
+    pub fn network_magic(&self) -> Option<u32> {
+        self.attributes.network_magic
+    }
+}
+
+impl FromStr for ByronAddress {
+    type Err = ByronAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base58(s)
+    }
+}
+
+/// Serializing into the base58 address format
+impl std::fmt::Display for ByronAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+impl From<ByronAddress> for String {
+    fn from(address: ByronAddress) -> Self {
+        address.to_base58()
+    }
+}
+
+impl TryFrom<String> for ByronAddress {
+    type Error = ByronAddressError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_base58(&s)
+    }
+}
+
+impl TryFromCSL<csl::ByronAddress> for ByronAddress {
+    fn try_from_csl(value: &csl::ByronAddress) -> Result<Self, TryFromCSLError> {
+        Self::from_bytes(&value.to_bytes())
+            .map_err(|err| TryFromCSLError::ImpossibleConversion(err.to_string()))
+    }
+}
+
+impl TryFromPLA<ByronAddress> for csl::ByronAddress {
+    fn try_from_pla(val: &ByronAddress) -> Result<Self, TryFromPLAError> {
+        csl::ByronAddress::from_bytes(val.to_bytes()).map_err(TryFromPLAError::CSLJsError)
+    }
+}
+
+impl From<ByronAddressType> for u64 {
+    fn from(address_type: ByronAddressType) -> Self {
+        match address_type {
+            ByronAddressType::PubKey => 0,
+            ByronAddressType::Script => 1,
+            ByronAddressType::Redeem => 2,
+        }
+    }
+}
+
+impl TryFrom<u64> for ByronAddressType {
+    type Error = ByronAddressError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ByronAddressType::PubKey),
+            1 => Ok(ByronAddressType::Script),
+            2 => Ok(ByronAddressType::Redeem),
+            _ => Err(ByronAddressError::UnknownAddressType(value)),
+        }
+    }
+}
+
+//////////
+// CBOR //
+//////////
+
+fn expect_array(raw: &mut Deserializer<Cursor<&[u8]>>, len: u64) -> Result<(), ByronAddressError> {
+    match raw.array()? {
+        Len::Len(got) if got == len => Ok(()),
+        got => Err(ByronAddressError::InvalidArrayLength { expected: len, got }),
+    }
+}
+
+fn expect_end(raw: Deserializer<Cursor<&[u8]>>, len: usize) -> Result<(), ByronAddressError> {
+    if raw.inner().position() == len as u64 {
+        Ok(())
+    } else {
+        Err(ByronAddressError::TrailingBytes)
+    }
+}
+
+/// Decode the attribute map, in which every value is a CBOR encoded item wrapped in a bytestring
+///
+/// Unknown attributes are skipped, they are still kept in the bytes of the address.
+fn decode_attributes(
+    raw: &mut Deserializer<Cursor<&[u8]>>,
+) -> Result<ByronAddressAttributes, ByronAddressError> {
+    let len = raw.map()?;
+    let mut attributes = ByronAddressAttributes::default();
+    let mut read = 0;
+
+    while match len {
+        Len::Len(n) => read < n,
+        Len::Indefinite => !(raw.cbor_type()? == Type::Special && raw.special_break()?),
+    } {
+        let key = raw.unsigned_integer()?;
+        let value = raw.bytes()?;
+        let mut value = Deserializer::from(Cursor::new(value.as_slice()));
+
+        match key {
+            DERIVATION_PATH_KEY => {
+                attributes.derivation_path = Some(LedgerBytes(value.bytes()?));
+            }
+            NETWORK_MAGIC_KEY => {
+                let magic = value.unsigned_integer()?;
+                attributes.network_magic = Some(
+                    u32::try_from(magic)
+                        .map_err(|_| ByronAddressError::InvalidNetworkMagic(magic))?,
+                );
+            }
+            _ => {}
+        }
+
+        read += 1;
+    }
+
+    Ok(attributes)
+}
+
+fn encode_attributes(
+    raw: &mut Serializer<Vec<u8>>,
+    attributes: &ByronAddressAttributes,
+) -> Result<(), ByronAddressError> {
+    let len = attributes.derivation_path.iter().count() + attributes.network_magic.iter().count();
+    raw.write_map(Len::Len(len as u64))?;
+
+    if let Some(derivation_path) = &attributes.derivation_path {
+        let mut value = Serializer::new_vec();
+        value.write_bytes(&derivation_path.0)?;
+        raw.write_unsigned_integer(DERIVATION_PATH_KEY)?
+            .write_bytes(value.finalize())?;
+    }
+
+    if let Some(network_magic) = attributes.network_magic {
+        let mut value = Serializer::new_vec();
+        value.write_unsigned_integer(network_magic.into())?;
+        raw.write_unsigned_integer(NETWORK_MAGIC_KEY)?
+            .write_bytes(value.finalize())?;
+    }
+
+    Ok(())
+}
+
+/// CRC32 checksum (IEEE 802.3 polynomial)
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+////////////
+// Base58 //
+////////////
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn encode_base58(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+    // Little-endian base58 digits
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| BASE58_ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+fn decode_base58(s: &str) -> Result<Vec<u8>, ByronAddressError> {
+    let zeros = s.chars().take_while(|c| *c == '1').count();
+
+    // Little-endian bytes
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in s.chars().skip(zeros) {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|a| *a as char == c)
+            .ok_or(ByronAddressError::InvalidBase58Character(c))? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    Ok(std::iter::repeat_n(0, zeros)
+        .chain(bytes.into_iter().rev())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indefinite_length_attributes() {
+        let root = vec![7; ROOT_LENGTH];
+
+        let mut magic = Serializer::new_vec();
+        magic.write_unsigned_integer(1).unwrap();
+
+        let mut payload = Serializer::new_vec();
+        payload
+            .write_array(Len::Len(3))
+            .unwrap()
+            .write_bytes(&root)
+            .unwrap()
+            .write_map(Len::Indefinite)
+            .unwrap()
+            .write_unsigned_integer(NETWORK_MAGIC_KEY)
+            .unwrap()
+            .write_bytes(magic.finalize())
+            .unwrap()
+            .write_special(cbor_event::Special::Break)
+            .unwrap()
+            .write_unsigned_integer(0)
+            .unwrap();
+        let payload = payload.finalize();
+
+        let mut address = Serializer::new_vec();
+        address
+            .write_array(Len::Len(2))
+            .unwrap()
+            .write_tag(EMBEDDED_CBOR_TAG)
+            .unwrap()
+            .write_bytes(&payload)
+            .unwrap()
+            .write_unsigned_integer(crc32(&payload).into())
+            .unwrap();
+
+        let address = ByronAddress::from_bytes(&address.finalize()).unwrap();
+
+        assert_eq!(address.root(), &LedgerBytes(root));
+        assert_eq!(address.network_magic(), Some(1));
+        assert_eq!(address.address_type(), ByronAddressType::PubKey);
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn base58_roundtrip() {
+        for bytes in [vec![], vec![0], vec![0, 0, 1, 2], vec![255; 40]] {
+            assert_eq!(decode_base58(&encode_base58(&bytes)).unwrap(), bytes);
+        }
+        assert_eq!(encode_base58(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(encode_base58(&[0, 0, 40, 127]), "1145k");
+    }
+}
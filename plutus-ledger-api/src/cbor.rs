@@ -0,0 +1,94 @@
+//! Helpers to calculate the size of CBOR serialized ledger types, without serializing them.
+//!
+//! The sizes follow the encoding used by cardano-serialization-lib.
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use crate::plutus_data::PlutusData;
+
+/// Maximum size of a bounded bytestring chunk in PlutusData
+const BOUNDED_BYTES_CHUNK_SIZE: usize = 64;
+
+/// Size of the CBOR head (major type and argument)
+pub(crate) fn head_size(arg: u64) -> usize {
+    match arg {
+        0..=23 => 1,
+        24..=0xff => 2,
+        0x100..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+/// Size of a definite length CBOR bytestring
+pub(crate) fn bytes_size(len: usize) -> usize {
+    head_size(len as u64) + len
+}
+
+/// Size of a PlutusData bytestring, that is chunked when longer than 64 bytes
+fn bounded_bytes_size(len: usize) -> usize {
+    if len <= BOUNDED_BYTES_CHUNK_SIZE {
+        bytes_size(len)
+    } else {
+        let full_chunks = len / BOUNDED_BYTES_CHUNK_SIZE;
+        let remainder = len % BOUNDED_BYTES_CHUNK_SIZE;
+
+        2 + full_chunks * bytes_size(BOUNDED_BYTES_CHUNK_SIZE)
+            + if remainder > 0 {
+                bytes_size(remainder)
+            } else {
+                0
+            }
+    }
+}
+
+/// Size of a CBOR integer, using bignum tags for values not fitting into 64 bits
+pub(crate) fn integer_size(int: &BigInt) -> usize {
+    // Negative integers are serialized as `-1 - n`
+    let magnitude = if int.is_negative() {
+        -int - 1
+    } else {
+        int.clone()
+    };
+
+    match u64::try_from(&magnitude) {
+        Ok(arg) => head_size(arg),
+        Err(_) => 1 + bounded_bytes_size(magnitude.to_bytes_be().1.len()),
+    }
+}
+
+/// Size of a CBOR serialized PlutusData
+pub(crate) fn plutus_data_size(data: &PlutusData) -> usize {
+    match data {
+        PlutusData::Constr(index, fields) => {
+            let fields_size = plutus_list_size(fields);
+
+            match u64::try_from(index) {
+                // Compact tags 121-127 and 1280-1400
+                Ok(0..=6) => 2 + fields_size,
+                Ok(7..=127) => 3 + fields_size,
+                // General form: tag 102 with `[ index, fields ]`
+                _ => 2 + 1 + integer_size(index) + fields_size,
+            }
+        }
+        PlutusData::Map(pairs) => {
+            head_size(pairs.len() as u64)
+                + pairs
+                    .iter()
+                    .map(|(k, v)| plutus_data_size(k) + plutus_data_size(v))
+                    .sum::<usize>()
+        }
+        PlutusData::List(list) => plutus_list_size(list),
+        PlutusData::Integer(int) => integer_size(int),
+        PlutusData::Bytes(bytes) => bounded_bytes_size(bytes.len()),
+    }
+}
+
+/// Size of a list of PlutusData, using indefinite length encoding for non-empty lists
+fn plutus_list_size(list: &[PlutusData]) -> usize {
+    if list.is_empty() {
+        1
+    } else {
+        2 + list.iter().map(plutus_data_size).sum::<usize>()
+    }
+}
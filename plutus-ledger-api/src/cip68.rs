@@ -0,0 +1,73 @@
+//! Metadata datum of [CIP-68](https://cips.cardano.org/cip/CIP-0068) reference tokens
+use num_bigint::BigInt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate as plutus_ledger_api;
+use crate::plutus_data::{IsPlutusData, PlutusData};
+use crate::v1::assoc_map::AssocMap;
+
+////////////////
+// Cip68Datum //
+////////////////
+
+/// Datum held by the reference NFT (label 100) of a CIP-68 asset
+///
+/// Metadata keys are encoded as UTF-8 bytestrings, values are left as arbitrary PlutusData
+#[derive(Clone, Debug, PartialEq, Eq, IsPlutusData)]
+#[is_plutus_data_derive_strategy = "Constr"]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cip68Datum {
+    pub metadata: AssocMap<String, PlutusData>,
+    pub version: BigInt,
+    pub extra: PlutusData,
+}
+
+impl Cip68Datum {
+    /// Construct a datum with empty metadata and no extra data
+    pub fn new(version: BigInt) -> Self {
+        Cip68Datum {
+            metadata: AssocMap::new(),
+            version,
+            extra: PlutusData::constr(0, Vec::new()),
+        }
+    }
+
+    /// Lookup a metadata field
+    pub fn get(&self, key: &str) -> Option<&PlutusData> {
+        self.metadata
+            .0
+            .iter()
+            .find_map(|(k, v)| if k == key { Some(v) } else { None })
+    }
+
+    /// Insert a metadata field, returning the previous value if there was one
+    pub fn insert(&mut self, key: &str, value: impl IsPlutusData) -> Option<PlutusData> {
+        self.metadata
+            .insert(key.to_string(), value.to_plutus_data())
+    }
+
+    /// Lookup a metadata field and decode it as a UTF-8 string
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.get(key)
+            .and_then(|value| String::from_plutus_data(value).ok())
+    }
+
+    pub fn name(&self) -> Option<String> {
+        self.get_string("name")
+    }
+
+    pub fn image(&self) -> Option<String> {
+        self.get_string("image")
+    }
+
+    pub fn description(&self) -> Option<String> {
+        self.get_string("description")
+    }
+
+    /// Number of decimals of a fungible token (label 333)
+    pub fn decimals(&self) -> Option<BigInt> {
+        self.get("decimals")
+            .and_then(|value| BigInt::from_plutus_data(value).ok())
+    }
+}
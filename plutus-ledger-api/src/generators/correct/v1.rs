@@ -1,18 +1,25 @@
 //! Proptest strategies for Plutus V1 types
 //!
 //! These strategies always return valid values.
+use crate::byron::{ByronAddress, ByronAddressAttributes, ByronAddressType};
 use crate::feature_traits::FeatureTraits;
 use crate::generators::correct::primitive::{arb_bool, arb_bytes, arb_integer, arb_natural};
+use crate::native_script::NativeScript;
 use crate::plutus_data::PlutusData;
 use crate::v1::address::{
-    Address, CertificateIndex, ChainPointer, Credential, Slot, StakingCredential, TransactionIndex,
+    Address, CertificateIndex, ChainPointer, Credential, LedgerAddress, RewardAddress, Slot,
+    StakingCredential, TransactionIndex,
 };
 use crate::v1::assoc_map::AssocMap;
-use crate::v1::crypto::{Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash, StakePubKeyHash};
+use crate::v1::crypto::{
+    Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash, PoolId, StakePubKeyHash, VrfKeyHash,
+};
 use crate::v1::datum::{Datum, DatumHash};
 use crate::v1::interval::{Extended, Interval, LowerBound, PlutusInterval, UpperBound};
 use crate::v1::redeemer::{Redeemer, RedeemerHash};
-use crate::v1::script::{MintingPolicyHash, ScriptHash, ValidatorHash};
+use crate::v1::script::{
+    MintingPolicyHash, PlutusLanguage, PlutusScript, ScriptHash, ValidatorHash,
+};
 use crate::v1::transaction::{
     DCert, POSIXTime, ScriptContext, ScriptPurpose, TransactionHash, TransactionInfo,
     TransactionInput, TransactionOutput, TxInInfo,
@@ -25,7 +32,6 @@ use proptest::collection::vec;
 use proptest::option;
 use proptest::prelude::{any, prop_oneof, Just};
 use proptest::strategy::Strategy;
-use std::collections::BTreeMap;
 
 /// Strategy to generate an arbitrary bytestring with a fixed length
 pub fn arb_ledger_bytes(length: usize) -> impl Strategy<Value = LedgerBytes> {
@@ -79,6 +85,45 @@ pub fn arb_script_hash() -> impl Strategy<Value = ScriptHash> {
     arb_ledger_bytes(28).prop_map(ScriptHash)
 }
 
+/// Strategy to generate a Plutus language version
+pub fn arb_plutus_language() -> impl Strategy<Value = PlutusLanguage> {
+    prop_oneof![
+        Just(PlutusLanguage::V1),
+        Just(PlutusLanguage::V2),
+        Just(PlutusLanguage::V3)
+    ]
+}
+
+/// Strategy to generate a Plutus script
+///
+/// The flat encoded program is arbitrary, apart from its leading major version
+pub fn arb_plutus_script() -> impl Strategy<Value = PlutusScript> {
+    (arb_plutus_language(), arb_bytes()).prop_map(|(language, program)| {
+        PlutusScript::from_flat(language, &[vec![1], program].concat())
+    })
+}
+
+/// Strategy to generate an arbitrary native script with a maximum depth of 3 recursions
+pub fn arb_native_script() -> impl Strategy<Value = NativeScript> {
+    arb_native_script_leaf().prop_recursive(3, 32, 5, |arb_script| {
+        prop_oneof![
+            vec(arb_script.clone(), 0..5).prop_map(NativeScript::All),
+            vec(arb_script.clone(), 0..5).prop_map(NativeScript::Any),
+            (0..6u32, vec(arb_script.clone(), 0..5))
+                .prop_map(|(n, scripts)| NativeScript::AtLeast(n, scripts)),
+        ]
+    })
+}
+
+/// Leaf generator for NativeScript recursive generator
+fn arb_native_script_leaf() -> impl Strategy<Value = NativeScript> {
+    prop_oneof![
+        arb_ed25519_pub_key_hash().prop_map(NativeScript::Sig),
+        any::<u64>().prop_map(NativeScript::Before),
+        any::<u64>().prop_map(NativeScript::After),
+    ]
+}
+
 /// Strategy to generate a Value
 ///
 /// This generator will try to balance the result, such that there's a 50% chance that Ada is
@@ -86,11 +131,8 @@ pub fn arb_script_hash() -> impl Strategy<Value = ScriptHash> {
 pub fn arb_value() -> impl Strategy<Value = Value> {
     prop_oneof![
         arb_native_tokens(),
-        (arb_native_tokens(), arb_natural(2)).prop_map(|(Value(outer_dict), amount)| {
-            let mut outer_dict = outer_dict.clone();
-            let inner_dict = BTreeMap::from([(TokenName::ada(), amount)]);
-            outer_dict.insert(CurrencySymbol::Ada, inner_dict);
-            Value(outer_dict)
+        (arb_native_tokens(), arb_natural(2)).prop_map(|(value, amount)| {
+            value.insert_token(&CurrencySymbol::Ada, &TokenName::ada(), &amount)
         })
     ]
 }
@@ -102,7 +144,7 @@ pub fn arb_native_tokens() -> impl Strategy<Value = Value> {
         btree_map(arb_token_name(), arb_natural(1), 5),
         5,
     )
-    .prop_map(Value)
+    .prop_map(Value::from)
 }
 
 /// Strategy to generate an arbitrary PlutusData with a maximum depth of 5 recursions
@@ -202,6 +244,9 @@ where
     (lower_bound, upper_bound).prop_flat_map(|(lb, ub)| {
         prop_oneof![
             4 => Just(Interval::Finite(lb.clone(), ub.clone())),
+            4 => Just(Interval::FiniteStartAfter(lb.clone(), ub.clone())),
+            4 => Just(Interval::FiniteEndBefore(lb.clone(), ub.clone())),
+            4 => Just(Interval::FiniteOpen(lb.clone(), ub.clone())),
             4 => Just(Interval::StartAt(lb.clone())),
             4 => Just(Interval::StartAfter(lb)),
             4 => Just(Interval::EndAt(ub.clone())),
@@ -255,6 +300,39 @@ pub fn arb_address() -> impl Strategy<Value = Address> {
     )
 }
 
+/// Strategy to generate a Byron bootstrap address
+pub fn arb_byron_address() -> impl Strategy<Value = ByronAddress> {
+    (
+        arb_ledger_bytes(28),
+        option::of(arb_bytes().prop_map(LedgerBytes)),
+        option::of(any::<u32>()),
+        prop_oneof![
+            Just(ByronAddressType::PubKey),
+            Just(ByronAddressType::Script),
+            Just(ByronAddressType::Redeem)
+        ],
+    )
+        .prop_map(|(root, derivation_path, network_magic, address_type)| {
+            ByronAddress::new(
+                root,
+                ByronAddressAttributes {
+                    derivation_path,
+                    network_magic,
+                },
+                address_type,
+            )
+            .unwrap()
+        })
+}
+
+/// Strategy to generate a Shelley or Byron ledger address
+pub fn arb_ledger_address() -> impl Strategy<Value = LedgerAddress> {
+    prop_oneof![
+        arb_address().prop_map(LedgerAddress::Shelley),
+        arb_byron_address().prop_map(LedgerAddress::Byron)
+    ]
+}
+
 /// Strategy to generate a chain pointer
 pub fn arb_chain_pointer() -> impl Strategy<Value = ChainPointer> {
     (arb_slot(), arb_transaction_index(), arb_certificate_index()).prop_map(
@@ -289,6 +367,14 @@ pub fn arb_staking_credential() -> impl Strategy<Value = StakingCredential> {
     ]
 }
 
+/// Strategy to generate a reward address, with a credential hash and a network tag
+pub fn arb_reward_address() -> impl Strategy<Value = RewardAddress> {
+    (arb_credential(), 0u8..16).prop_map(|(credential, network_tag)| RewardAddress {
+        network_tag,
+        credential,
+    })
+}
+
 /// Strategy to generate a credential
 pub fn arb_credential() -> impl Strategy<Value = Credential> {
     prop_oneof![
@@ -340,16 +426,26 @@ pub fn arb_payment_pub_key_hash() -> impl Strategy<Value = PaymentPubKeyHash> {
     arb_ed25519_pub_key_hash().prop_map(PaymentPubKeyHash)
 }
 
+/// Strategy to generate a PoolId
+pub fn arb_pool_id() -> impl Strategy<Value = PoolId> {
+    arb_ed25519_pub_key_hash().prop_map(PoolId)
+}
+
+/// Strategy to generate a VrfKeyHash
+pub fn arb_vrf_key_hash() -> impl Strategy<Value = VrfKeyHash> {
+    arb_ledger_bytes(32).prop_map(VrfKeyHash)
+}
+
 /// Strategy to generate a DCert
 pub fn arb_d_cert() -> impl Strategy<Value = DCert> {
     prop_oneof![
         arb_staking_credential().prop_map(DCert::DelegRegKey),
         arb_staking_credential().prop_map(DCert::DelegDeRegKey),
-        (arb_staking_credential(), arb_payment_pub_key_hash())
-            .prop_map(|(sc, pkh)| DCert::DelegDelegate(sc, pkh)),
-        (arb_payment_pub_key_hash(), arb_payment_pub_key_hash())
-            .prop_map(|(p1, p2)| DCert::PoolRegister(p1, p2)),
-        (arb_payment_pub_key_hash(), arb_natural(1)).prop_map(|(pkh, i)| DCert::PoolRetire(pkh, i)),
+        (arb_staking_credential(), arb_pool_id())
+            .prop_map(|(sc, pool_id)| DCert::DelegDelegate(sc, pool_id)),
+        (arb_pool_id(), arb_vrf_key_hash())
+            .prop_map(|(pool_id, vrf)| DCert::PoolRegister(pool_id, vrf)),
+        (arb_pool_id(), arb_natural(1)).prop_map(|(pool_id, i)| DCert::PoolRetire(pool_id, i)),
         Just(DCert::Genesis),
         Just(DCert::Mir)
     ]
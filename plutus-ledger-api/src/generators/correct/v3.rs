@@ -5,7 +5,7 @@
 use proptest::{
     collection::vec,
     option,
-    prelude::{Just, Strategy},
+    prelude::{any, Just, Strategy},
     prop_oneof,
 };
 
@@ -13,8 +13,8 @@ use crate::{
     generators::correct::{
         primitive::arb_integer,
         v1::{
-            arb_currency_symbol, arb_datum, arb_ed25519_pub_key_hash, arb_lovelace,
-            arb_payment_pub_key_hash, arb_stake_pub_key_hash,
+            arb_currency_symbol, arb_datum, arb_lovelace, arb_payment_pub_key_hash, arb_pool_id,
+            arb_vrf_key_hash,
         },
     },
     v3::{
@@ -78,9 +78,9 @@ pub fn arb_d_rep() -> impl Strategy<Value = DRep> {
 /// Strategy to generate delegatees
 pub fn arb_delegatee() -> impl Strategy<Value = Delegatee> {
     prop_oneof![
-        arb_stake_pub_key_hash().prop_map(Delegatee::Stake),
+        arb_pool_id().prop_map(Delegatee::Stake),
         arb_d_rep().prop_map(Delegatee::Vote),
-        (arb_stake_pub_key_hash(), arb_d_rep()).prop_map(|(h, r)| Delegatee::StakeVote(h, r))
+        (arb_pool_id(), arb_d_rep()).prop_map(|(h, r)| Delegatee::StakeVote(h, r))
     ]
 }
 
@@ -96,9 +96,9 @@ pub fn arb_tx_cert() -> impl Strategy<Value = TxCert> {
         (arb_d_rep_credential(), arb_lovelace()).prop_map(|(d, l)| TxCert::RegDRep(d, l)),
         arb_d_rep_credential().prop_map(TxCert::UpdateDRep),
         (arb_d_rep_credential(), arb_lovelace()).prop_map(|(d, l)| TxCert::UnRegDRep(d, l)),
-        (arb_ed25519_pub_key_hash(), arb_ed25519_pub_key_hash())
-            .prop_map(|(pkh1, pkh2)| TxCert::PoolRegister(pkh1, pkh2)),
-        (arb_ed25519_pub_key_hash(), arb_integer()).prop_map(|(pkh, i)| TxCert::PoolRetire(pkh, i)),
+        (arb_pool_id(), arb_vrf_key_hash())
+            .prop_map(|(pool_id, vrf)| TxCert::PoolRegister(pool_id, vrf)),
+        (arb_pool_id(), arb_integer()).prop_map(|(pool_id, i)| TxCert::PoolRetire(pool_id, i)),
         (
             arb_cold_committee_credential(),
             arb_hot_committee_credential()
@@ -113,7 +113,7 @@ pub fn arb_voter() -> impl Strategy<Value = Voter> {
     prop_oneof![
         arb_hot_committee_credential().prop_map(Voter::CommitteeVoter),
         arb_d_rep_credential().prop_map(Voter::DRepVoter),
-        arb_ed25519_pub_key_hash().prop_map(Voter::StakePoolVoter)
+        arb_pool_id().prop_map(Voter::StakePoolVoter)
     ]
 }
 
@@ -124,10 +124,8 @@ pub fn arb_vote() -> impl Strategy<Value = Vote> {
 
 /// Strategy to generate governance action ids
 pub fn arb_governance_action_id() -> impl Strategy<Value = GovernanceActionId> {
-    (arb_transaction_hash(), arb_integer()).prop_map(|(tx_id, gov_action_id)| GovernanceActionId {
-        tx_id,
-        gov_action_id,
-    })
+    (arb_transaction_hash(), any::<u16>())
+        .prop_map(|(tx_id, index)| GovernanceActionId::new(tx_id, index))
 }
 
 /// Strategy to generate committees
@@ -3,7 +3,7 @@ use crate::{
     plutus_data::PlutusData,
     v1::{
         address::{Address, Credential, StakingCredential},
-        crypto::{Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash},
+        crypto::{Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash, PoolId},
         datum::{Datum, DatumHash},
         interval::{Interval, PlutusInterval},
         redeemer::{Redeemer, RedeemerHash},
@@ -126,12 +126,16 @@ pub fn sample_payment_pub_key_hash() -> PaymentPubKeyHash {
     PaymentPubKeyHash(sample_ed25519_pub_key_hash())
 }
 
+pub fn sample_pool_id() -> PoolId {
+    PoolId(sample_ed25519_pub_key_hash())
+}
+
 pub fn sample_script_purpose() -> ScriptPurpose {
     ScriptPurpose::Minting(sample_currency_symbol())
 }
 
 pub fn sample_dcert() -> DCert {
-    DCert::DelegDelegate(sample_staking_credential(), sample_payment_pub_key_hash())
+    DCert::DelegDelegate(sample_staking_credential(), sample_pool_id())
 }
 
 pub fn sample_transaction_info() -> TransactionInfo {
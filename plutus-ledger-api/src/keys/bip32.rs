@@ -0,0 +1,175 @@
+//! BIP32-Ed25519 hierarchical deterministic keys
+use std::fmt;
+
+use anyhow::anyhow;
+use cryptoxide::ed25519::extended_to_public;
+use cryptoxide::hmac::Hmac;
+use cryptoxide::mac::Mac;
+use cryptoxide::pbkdf2::pbkdf2;
+use cryptoxide::sha2::Sha512;
+
+use crate::aux::guard_bytes;
+use crate::error::ConversionError;
+use crate::keys::ed25519::{Ed25519PrivateKey, Ed25519PubKey, Ed25519Signature};
+use crate::keys::mnemonic::Mnemonic;
+use crate::v1::crypto::LedgerBytes;
+
+/////////////////////
+// Bip32PrivateKey //
+/////////////////////
+
+/// Offset of hardened derivation indices
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// Hardened derivation index, written as `index'` in derivation paths
+pub fn harden(index: u32) -> u32 {
+    index | HARDENED
+}
+
+/// BIP32-Ed25519 extended private key
+///
+/// The key is a 64 bytes extended Ed25519 secret key followed by a 32 bytes chain code. Child keys
+/// are derived with the V2 scheme of
+/// [BIP32-Ed25519](https://input-output-hk.github.io/adrestia/static/Ed25519_BIP.pdf), as in
+/// Cardano wallets. The `Debug` instance doesn't show the key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Bip32PrivateKey([u8; 96]);
+
+impl Bip32PrivateKey {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        let bytes: [u8; 96] = guard_bytes("Bip32PrivateKey", bytes, 96)?
+            .try_into()
+            .unwrap();
+
+        if bytes[0] & 0b0000_0111 != 0 || bytes[31] & 0b0010_0000 != 0 {
+            return Err(ConversionError::ParseError(anyhow!(
+                "Bip32PrivateKey is not a valid extended Ed25519 secret key"
+            )));
+        }
+
+        Ok(Bip32PrivateKey(bytes))
+    }
+
+    /// Root key of a wallet from the entropy of its mnemonic, following the Icarus scheme of
+    /// [CIP-3](https://cips.cardano.org/cip/CIP-0003)
+    pub fn from_bip39_entropy(entropy: &[u8], passphrase: &[u8]) -> Self {
+        let mut bytes = [0; 96];
+        pbkdf2(
+            &mut Hmac::new(Sha512::new(), passphrase),
+            entropy,
+            4096,
+            &mut bytes,
+        );
+
+        bytes[0] &= 0b1111_1000;
+        bytes[31] &= 0b0001_1111;
+        bytes[31] |= 0b0100_0000;
+
+        Bip32PrivateKey(bytes)
+    }
+
+    /// Root key of a wallet, with an optional (empty) passphrase
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> Self {
+        Self::from_bip39_entropy(mnemonic.entropy(), passphrase.as_bytes())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn chain_code(&self) -> &[u8] {
+        &self.0[64..]
+    }
+
+    pub fn public_key(&self) -> Ed25519PubKey {
+        Ed25519PubKey(LedgerBytes(
+            extended_to_public(self.extended_secret()).to_vec(),
+        ))
+    }
+
+    /// Extended Ed25519 private key, without the chain code
+    pub fn to_private_key(&self) -> Ed25519PrivateKey {
+        Ed25519PrivateKey::from_extended_bytes(self.0[..64].to_vec()).unwrap()
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Ed25519Signature {
+        self.to_private_key().sign(message)
+    }
+
+    /// Derive the child key at an index, which is hardened from `HARDENED` onwards
+    pub fn derive(&self, index: u32) -> Self {
+        let public_key;
+        let (z_tag, chain_code_tag, key): (u8, u8, &[u8]) = if index >= HARDENED {
+            (0x00, 0x01, self.extended_secret())
+        } else {
+            public_key = self.public_key();
+            (0x02, 0x03, &public_key.0 .0)
+        };
+
+        let hmac = |tag: u8| {
+            let mut hmac = Hmac::new(Sha512::new(), self.chain_code());
+            hmac.input(&[tag]);
+            hmac.input(key);
+            hmac.input(&index.to_le_bytes());
+
+            let mut out = [0; 64];
+            hmac.raw_result(&mut out);
+            out
+        };
+
+        let z = hmac(z_tag);
+        let chain_code = hmac(chain_code_tag);
+
+        let mut child = [0; 96];
+        child[..32].copy_from_slice(&add_28_mul8(&self.0[..32], &z[..28]));
+        child[32..64].copy_from_slice(&add_256(&self.0[32..64], &z[32..]));
+        child[64..].copy_from_slice(&chain_code[32..]);
+
+        Bip32PrivateKey(child)
+    }
+
+    /// Derive the key at a path of indices, starting from this key
+    pub fn derive_path(&self, path: &[u32]) -> Self {
+        path.iter()
+            .fold(self.clone(), |key, index| key.derive(*index))
+    }
+
+    fn extended_secret(&self) -> &[u8; 64] {
+        self.0[..64].try_into().unwrap()
+    }
+}
+
+impl fmt::Debug for Bip32PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bip32PrivateKey(..)")
+    }
+}
+
+/// `x + 8 * y` on little-endian 256 bits integers, where `y` is 224 bits long
+fn add_28_mul8(x: &[u8], y: &[u8]) -> [u8; 32] {
+    let mut out = [0; 32];
+    let mut carry: u16 = 0;
+
+    for i in 0..32 {
+        let y = y.get(i).map_or(0, |y| u16::from(*y) << 3);
+        let r = u16::from(x[i]) + y + carry;
+        out[i] = r as u8;
+        carry = r >> 8;
+    }
+
+    out
+}
+
+/// `x + y` modulo 2^256 on little-endian 256 bits integers
+fn add_256(x: &[u8], y: &[u8]) -> [u8; 32] {
+    let mut out = [0; 32];
+    let mut carry: u16 = 0;
+
+    for i in 0..32 {
+        let r = u16::from(x[i]) + u16::from(y[i]) + carry;
+        out[i] = r as u8;
+        carry = r >> 8;
+    }
+
+    out
+}
@@ -0,0 +1,121 @@
+//! Accounts of HD wallets, following [CIP-1852](https://cips.cardano.org/cip/CIP-1852)
+use crate::keys::bip32::{harden, Bip32PrivateKey};
+use crate::keys::mnemonic::Mnemonic;
+use crate::network::Network;
+use crate::v1::address::{Address, Credential, RewardAddress, StakingCredential};
+use crate::v1::crypto::{PaymentPubKeyHash, StakePubKeyHash};
+
+/////////////
+// Account //
+/////////////
+
+/// Purpose of CIP-1852 derivation paths
+pub const PURPOSE: u32 = 1852;
+
+/// Coin type of Ada in derivation paths
+pub const COIN_TYPE: u32 = 1815;
+
+/// Role of a key in an account, the fourth index of its derivation path
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    /// Payment keys of receiving addresses
+    External,
+    /// Payment keys of change addresses
+    Internal,
+    /// Stake keys of staking credentials
+    Staking,
+}
+
+impl From<Role> for u32 {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::External => 0,
+            Role::Internal => 1,
+            Role::Staking => 2,
+        }
+    }
+}
+
+/// Account of an HD wallet, holding the private key at `m/1852'/1815'/account'`
+///
+/// Keys are derived at `m/1852'/1815'/account'/role/index`. Addresses use the stake key at index 0
+/// as their staking credential, like most wallets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    key: Bip32PrivateKey,
+}
+
+impl Account {
+    pub fn from_root_key(root_key: &Bip32PrivateKey, account: u32) -> Self {
+        Account {
+            key: root_key.derive_path(&[harden(PURPOSE), harden(COIN_TYPE), harden(account)]),
+        }
+    }
+
+    /// Account of the wallet of a mnemonic, with an optional (empty) passphrase
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str, account: u32) -> Self {
+        Self::from_root_key(
+            &Bip32PrivateKey::from_mnemonic(mnemonic, passphrase),
+            account,
+        )
+    }
+
+    pub fn key(&self) -> &Bip32PrivateKey {
+        &self.key
+    }
+
+    pub fn derive(&self, role: Role, index: u32) -> Bip32PrivateKey {
+        self.key.derive_path(&[role.into(), index])
+    }
+
+    pub fn payment_key(&self, index: u32) -> Bip32PrivateKey {
+        self.derive(Role::External, index)
+    }
+
+    pub fn change_key(&self, index: u32) -> Bip32PrivateKey {
+        self.derive(Role::Internal, index)
+    }
+
+    pub fn stake_key(&self) -> Bip32PrivateKey {
+        self.derive(Role::Staking, 0)
+    }
+
+    pub fn payment_pub_key_hash(&self, index: u32) -> PaymentPubKeyHash {
+        PaymentPubKeyHash(self.payment_key(index).public_key().hash())
+    }
+
+    pub fn change_pub_key_hash(&self, index: u32) -> PaymentPubKeyHash {
+        PaymentPubKeyHash(self.change_key(index).public_key().hash())
+    }
+
+    pub fn stake_pub_key_hash(&self) -> StakePubKeyHash {
+        StakePubKeyHash(self.stake_key().public_key().hash())
+    }
+
+    pub fn staking_credential(&self) -> StakingCredential {
+        StakingCredential::Hash(Credential::PubKey(self.stake_pub_key_hash().0))
+    }
+
+    /// Base address with the payment key at the index
+    pub fn base_address(&self, index: u32) -> Address {
+        Address {
+            credential: Credential::PubKey(self.payment_pub_key_hash(index).0),
+            staking_credential: Some(self.staking_credential()),
+        }
+    }
+
+    /// Enterprise address (without staking credential) with the payment key at the index
+    pub fn enterprise_address(&self, index: u32) -> Address {
+        Address {
+            credential: Credential::PubKey(self.payment_pub_key_hash(index).0),
+            staking_credential: None,
+        }
+    }
+
+    pub fn reward_address(&self, network: &Network) -> RewardAddress {
+        RewardAddress {
+            network_tag: network.network_id,
+            credential: Credential::PubKey(self.stake_pub_key_hash().0),
+        }
+    }
+}
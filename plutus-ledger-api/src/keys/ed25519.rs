@@ -0,0 +1,188 @@
+//! Ed25519 keys and signatures
+use std::fmt;
+
+use cardano_serialization_lib as csl;
+use cryptoxide::ed25519;
+use cryptoxide::hashing::blake2b_224;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::aux::guard_bytes;
+use crate::csl::csl_to_pla::{FromCSL, TryFromCSL, TryFromCSLError};
+use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError};
+use crate::error::ConversionError;
+use crate::v1::crypto::{Ed25519PubKeyHash, LedgerBytes};
+
+///////////////////
+// Ed25519PubKey //
+///////////////////
+
+/// Ed25519 public key, also known as verification key
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ed25519PubKey(pub LedgerBytes);
+
+impl Ed25519PubKey {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(Ed25519PubKey(LedgerBytes(guard_bytes(
+            "Ed25519PubKey",
+            bytes,
+            32,
+        )?)))
+    }
+
+    /// Blake2b-224 hash of the key, used in credentials and required signers
+    pub fn hash(&self) -> Ed25519PubKeyHash {
+        Ed25519PubKeyHash(LedgerBytes(blake2b_224(&self.0 .0).to_vec()))
+    }
+
+    /// Verify a signature of a message, returning false for invalid keys and signatures
+    pub fn verify(&self, message: &[u8], signature: &Ed25519Signature) -> bool {
+        match (
+            <&[u8; 32]>::try_from(self.0 .0.as_slice()),
+            <&[u8; 64]>::try_from(signature.0 .0.as_slice()),
+        ) {
+            (Ok(public_key), Ok(signature)) => ed25519::verify(message, public_key, signature),
+            _ => false,
+        }
+    }
+}
+
+impl FromCSL<csl::PublicKey> for Ed25519PubKey {
+    fn from_csl(value: &csl::PublicKey) -> Self {
+        Ed25519PubKey(LedgerBytes(value.as_bytes()))
+    }
+}
+
+impl TryFromPLA<Ed25519PubKey> for csl::PublicKey {
+    fn try_from_pla(val: &Ed25519PubKey) -> Result<Self, TryFromPLAError> {
+        csl::PublicKey::from_bytes(&val.0 .0).map_err(TryFromPLAError::CSLJsError)
+    }
+}
+
+///////////////////////
+// Ed25519PrivateKey //
+///////////////////////
+
+/// Ed25519 private key, also known as signing key
+///
+/// The key is either a normal 32 bytes secret key (seed), or a 64 bytes extended secret key, like
+/// the keys derived from a `Bip32PrivateKey`. The `Debug` instance doesn't show the key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Ed25519PrivateKey(PrivateKeyBytes);
+
+#[derive(Clone, PartialEq, Eq)]
+enum PrivateKeyBytes {
+    Normal([u8; 32]),
+    Extended([u8; 64]),
+}
+
+impl Ed25519PrivateKey {
+    pub fn from_normal_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(Ed25519PrivateKey(PrivateKeyBytes::Normal(
+            guard_bytes("Ed25519PrivateKey", bytes, 32)?
+                .try_into()
+                .unwrap(),
+        )))
+    }
+
+    pub fn from_extended_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(Ed25519PrivateKey(PrivateKeyBytes::Extended(
+            guard_bytes("Ed25519PrivateKey", bytes, 64)?
+                .try_into()
+                .unwrap(),
+        )))
+    }
+
+    /// Raw bytes of the key, 32 bytes long for normal keys and 64 bytes long for extended ones
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.0 {
+            PrivateKeyBytes::Normal(bytes) => bytes,
+            PrivateKeyBytes::Extended(bytes) => bytes,
+        }
+    }
+
+    pub fn is_extended(&self) -> bool {
+        matches!(self.0, PrivateKeyBytes::Extended(_))
+    }
+
+    pub fn public_key(&self) -> Ed25519PubKey {
+        let public_key = match &self.0 {
+            PrivateKeyBytes::Normal(bytes) => ed25519::keypair(bytes).1,
+            PrivateKeyBytes::Extended(bytes) => ed25519::extended_to_public(bytes),
+        };
+
+        Ed25519PubKey(LedgerBytes(public_key.to_vec()))
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Ed25519Signature {
+        let signature = match &self.0 {
+            PrivateKeyBytes::Normal(bytes) => {
+                ed25519::signature(message, &ed25519::keypair(bytes).0)
+            }
+            PrivateKeyBytes::Extended(bytes) => ed25519::signature_extended(message, bytes),
+        };
+
+        Ed25519Signature(LedgerBytes(signature.to_vec()))
+    }
+}
+
+impl fmt::Debug for Ed25519PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ed25519PrivateKey(..)")
+    }
+}
+
+impl TryFromCSL<csl::PrivateKey> for Ed25519PrivateKey {
+    fn try_from_csl(value: &csl::PrivateKey) -> Result<Self, TryFromCSLError> {
+        let bytes = value.as_bytes();
+
+        match bytes.len() {
+            32 => Ed25519PrivateKey::from_normal_bytes(bytes),
+            _ => Ed25519PrivateKey::from_extended_bytes(bytes),
+        }
+        .map_err(|err| TryFromCSLError::ImpossibleConversion(err.to_string()))
+    }
+}
+
+impl TryFromPLA<Ed25519PrivateKey> for csl::PrivateKey {
+    fn try_from_pla(val: &Ed25519PrivateKey) -> Result<Self, TryFromPLAError> {
+        match val.0 {
+            PrivateKeyBytes::Normal(bytes) => csl::PrivateKey::from_normal_bytes(&bytes),
+            PrivateKeyBytes::Extended(bytes) => csl::PrivateKey::from_extended_bytes(&bytes),
+        }
+        .map_err(TryFromPLAError::CSLJsError)
+    }
+}
+
+//////////////////////
+// Ed25519Signature //
+//////////////////////
+
+/// 64 bytes Ed25519 signature
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ed25519Signature(pub LedgerBytes);
+
+impl Ed25519Signature {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(Ed25519Signature(LedgerBytes(guard_bytes(
+            "Ed25519Signature",
+            bytes,
+            64,
+        )?)))
+    }
+}
+
+impl FromCSL<csl::Ed25519Signature> for Ed25519Signature {
+    fn from_csl(value: &csl::Ed25519Signature) -> Self {
+        Ed25519Signature(LedgerBytes(value.to_bytes()))
+    }
+}
+
+impl TryFromPLA<Ed25519Signature> for csl::Ed25519Signature {
+    fn try_from_pla(val: &Ed25519Signature) -> Result<Self, TryFromPLAError> {
+        csl::Ed25519Signature::from_bytes(val.0 .0.to_owned())
+            .map_err(TryFromPLAError::CSLDeserializeError)
+    }
+}
@@ -0,0 +1,128 @@
+//! BIP-39 mnemonics
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use cryptoxide::hashing::sha256;
+
+//////////////
+// Mnemonic //
+//////////////
+
+/// English word list of [BIP-39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki)
+const ENGLISH_WORDS: &str = include_str!("bip39_english.txt");
+
+/// BIP-39 mnemonic of 12, 15, 18, 21 or 24 English words
+///
+/// The mnemonic is a secret, so its `Debug` instance only shows the number of words.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    entropy: Vec<u8>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MnemonicError {
+    #[error("Entropy must be 16, 20, 24, 28 or 32 bytes long, but got {0} bytes")]
+    InvalidEntropyLength(usize),
+
+    #[error("Mnemonic must have 12, 15, 18, 21 or 24 words, but got {0} words")]
+    InvalidWordCount(usize),
+
+    #[error("Unknown mnemonic word '{0}'")]
+    UnknownWord(String),
+
+    #[error("Invalid mnemonic checksum")]
+    InvalidChecksum,
+}
+
+impl Mnemonic {
+    pub fn from_entropy(entropy: Vec<u8>) -> Result<Self, MnemonicError> {
+        match entropy.len() {
+            16 | 20 | 24 | 28 | 32 => Ok(Mnemonic { entropy }),
+            len => Err(MnemonicError::InvalidEntropyLength(len)),
+        }
+    }
+
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.entropy.len() * 3 / 4
+    }
+
+    pub fn words(&self) -> Vec<&'static str> {
+        // The checksum is at most 8 bits long, so the first byte of the hash is enough
+        let bits = [self.entropy.as_slice(), &sha256(&self.entropy)[..1]].concat();
+
+        (0..self.word_count())
+            .map(|word| {
+                let index = (0..11).fold(0, |index, bit| {
+                    (index << 1) | usize::from(get_bit(&bits, word * 11 + bit))
+                });
+
+                wordlist()[index]
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = MnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+            return Err(MnemonicError::InvalidWordCount(words.len()));
+        }
+
+        let mut bits = vec![0; (words.len() * 11).div_ceil(8)];
+        for (word_index, word) in words.iter().enumerate() {
+            let index = wordlist()
+                .binary_search(word)
+                .map_err(|_| MnemonicError::UnknownWord(word.to_string()))?;
+
+            for bit in 0..11 {
+                if index & (1 << (10 - bit)) != 0 {
+                    set_bit(&mut bits, word_index * 11 + bit);
+                }
+            }
+        }
+
+        let entropy_len = words.len() * 4 / 3;
+        let mnemonic = Mnemonic::from_entropy(bits[..entropy_len].to_vec())?;
+
+        if mnemonic.words() == words {
+            Ok(mnemonic)
+        } else {
+            Err(MnemonicError::InvalidChecksum)
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.words().join(" "))
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mnemonic({} words)", self.word_count())
+    }
+}
+
+fn wordlist() -> &'static [&'static str] {
+    static WORDLIST: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    WORDLIST.get_or_init(|| ENGLISH_WORDS.lines().collect())
+}
+
+/// Bit of a big-endian bit string
+fn get_bit(bytes: &[u8], bit: usize) -> bool {
+    bytes[bit / 8] & (0x80 >> (bit % 8)) != 0
+}
+
+fn set_bit(bytes: &mut [u8], bit: usize) {
+    bytes[bit / 8] |= 0x80 >> (bit % 8);
+}
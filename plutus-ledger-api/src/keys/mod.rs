@@ -0,0 +1,7 @@
+//! Keys of Cardano wallets: BIP-39 mnemonics, BIP32-Ed25519 key derivation, CIP-1852 accounts,
+//! and signing of transactions with vkey witnesses
+pub mod bip32;
+pub mod cip1852;
+pub mod ed25519;
+pub mod mnemonic;
+pub mod witness;
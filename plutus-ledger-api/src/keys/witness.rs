@@ -0,0 +1,116 @@
+//! Vkey witnesses, the signatures of a transaction
+use cardano_serialization_lib as csl;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csl::csl_to_pla::FromCSL;
+use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
+use crate::keys::bip32::Bip32PrivateKey;
+use crate::keys::ed25519::{Ed25519PrivateKey, Ed25519PubKey, Ed25519Signature};
+use crate::v1::crypto::PaymentPubKeyHash;
+use crate::v1::transaction::TransactionHash;
+
+/////////////////
+// VKeyWitness //
+/////////////////
+
+/// Signature of a transaction id, with the public key of the signer
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VKeyWitness {
+    pub vkey: Ed25519PubKey,
+    pub signature: Ed25519Signature,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum WitnessError {
+    #[error("Invalid signature of public key {0:?}")]
+    InvalidSignature(Ed25519PubKey),
+
+    #[error("Missing signature of required signer {0:?}")]
+    MissingSignature(PaymentPubKeyHash),
+}
+
+impl VKeyWitness {
+    /// Sign a transaction id
+    pub fn new(tx_hash: &TransactionHash, key: &Ed25519PrivateKey) -> Self {
+        VKeyWitness {
+            vkey: key.public_key(),
+            signature: key.sign(&tx_hash.0 .0),
+        }
+    }
+
+    /// Sign a transaction id with a key of an HD wallet
+    pub fn from_bip32(tx_hash: &TransactionHash, key: &Bip32PrivateKey) -> Self {
+        Self::new(tx_hash, &key.to_private_key())
+    }
+
+    pub fn verify(&self, tx_hash: &TransactionHash) -> bool {
+        self.vkey.verify(&tx_hash.0 .0, &self.signature)
+    }
+
+    /// Hash of the public key, as it appears in `signatories`
+    pub fn pub_key_hash(&self) -> PaymentPubKeyHash {
+        PaymentPubKeyHash(self.vkey.hash())
+    }
+}
+
+/// Verify witnesses against the required signers (`signatories`) of a transaction
+///
+/// All witnesses must be valid signatures of the transaction id, and every required signer must
+/// have a witness. Witnesses of other keys (e.g. of the owners of the inputs) are allowed.
+pub fn verify_witnesses(
+    tx_hash: &TransactionHash,
+    signatories: &[PaymentPubKeyHash],
+    witnesses: &[VKeyWitness],
+) -> Result<(), WitnessError> {
+    if let Some(witness) = witnesses.iter().find(|witness| !witness.verify(tx_hash)) {
+        return Err(WitnessError::InvalidSignature(witness.vkey.clone()));
+    }
+
+    let signers: Vec<PaymentPubKeyHash> = witnesses.iter().map(VKeyWitness::pub_key_hash).collect();
+
+    match signatories
+        .iter()
+        .find(|signatory| !signers.contains(signatory))
+    {
+        Some(signatory) => Err(WitnessError::MissingSignature(signatory.clone())),
+        None => Ok(()),
+    }
+}
+
+impl FromCSL<csl::Vkeywitness> for VKeyWitness {
+    fn from_csl(value: &csl::Vkeywitness) -> Self {
+        VKeyWitness {
+            vkey: Ed25519PubKey::from_csl(&value.vkey().public_key()),
+            signature: Ed25519Signature::from_csl(&value.signature()),
+        }
+    }
+}
+
+impl FromCSL<csl::Vkeywitnesses> for Vec<VKeyWitness> {
+    fn from_csl(value: &csl::Vkeywitnesses) -> Self {
+        (0..value.len())
+            .map(|idx| VKeyWitness::from_csl(&value.get(idx)))
+            .collect()
+    }
+}
+
+impl TryFromPLA<VKeyWitness> for csl::Vkeywitness {
+    fn try_from_pla(val: &VKeyWitness) -> Result<Self, TryFromPLAError> {
+        Ok(csl::Vkeywitness::new(
+            &csl::Vkey::new(&val.vkey.try_to_csl()?),
+            &val.signature.try_to_csl()?,
+        ))
+    }
+}
+
+impl TryFromPLA<Vec<VKeyWitness>> for csl::Vkeywitnesses {
+    fn try_from_pla(val: &Vec<VKeyWitness>) -> Result<Self, TryFromPLAError> {
+        val.iter()
+            .try_fold(csl::Vkeywitnesses::new(), |mut acc, witness| {
+                acc.add(&witness.try_to_csl()?);
+                Ok(acc)
+            })
+    }
+}
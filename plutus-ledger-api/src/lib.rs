@@ -1,9 +1,17 @@
+pub mod asset_registry;
+pub mod byron;
+pub(crate) mod cbor;
+pub mod cip68;
 pub(crate) mod feature_traits;
 pub mod generators;
 pub mod goldens;
+pub mod keys;
 #[cfg(feature = "lbf")]
 pub mod lamval;
+pub mod native_script;
+pub mod network;
 pub mod plutus_data;
+pub mod slot_config;
 pub mod v1;
 pub mod v2;
 pub mod v3;
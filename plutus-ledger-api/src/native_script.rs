@@ -0,0 +1,438 @@
+//! Native (simple) scripts, the multisig and timelock scripts introduced in the Allegra era
+//!
+//! Native scripts have no Plutus representation, but they can lock outputs, be used as minting
+//! policies, and be attached to outputs as reference scripts.
+use std::collections::BTreeSet;
+use std::io::Cursor;
+
+use cardano_serialization_lib as csl;
+use cbor_event::{de::Deserializer, se::Serializer, Len, Type};
+use cryptoxide::hashing::blake2b::Blake2b;
+#[cfg(feature = "serde")]
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE};
+use num_bigint::BigInt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csl::csl_to_pla::FromCSL;
+use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
+use crate::error::ConversionError;
+use crate::slot_config::{SlotConfig, ValidityRangeError};
+use crate::v1::address::{Address, Credential, Slot, StakingCredential};
+use crate::v1::crypto::{Ed25519PubKeyHash, LedgerBytes};
+use crate::v1::script::{MintingPolicyHash, ScriptHash, ValidatorHash};
+use crate::v1::transaction::POSIXTimeRange;
+use crate::v1::value::CurrencySymbol;
+
+//////////////////
+// NativeScript //
+//////////////////
+
+/// A native script
+///
+/// With the `serde` feature, native scripts are serialized in the JSON format of cardano-cli
+/// (e.g. `{ "type": "sig", "keyHash": "..." }`).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "NativeScriptJson", try_from = "NativeScriptJson")
+)]
+pub enum NativeScript {
+    /// Requires a signature of the key
+    Sig(Ed25519PubKeyHash),
+    /// Requires all the scripts to be satisfied
+    All(Vec<NativeScript>),
+    /// Requires at least one of the scripts to be satisfied
+    Any(Vec<NativeScript>),
+    /// Requires at least `n` of the scripts to be satisfied
+    AtLeast(u32, Vec<NativeScript>),
+    /// Requires the transaction to expire at or before the slot (`RequireTimeExpire`)
+    Before(u64),
+    /// Requires the transaction to be valid only from the slot onwards (`RequireTimeStart`)
+    After(u64),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum NativeScriptError {
+    #[error("Unable to decode the CBOR of a native script: {0}")]
+    Cbor(#[from] cbor_event::Error),
+
+    #[error("Expected a CBOR array of length {expected}, but got {got:?}")]
+    InvalidArrayLength { expected: u64, got: Len },
+
+    #[error("Unknown native script tag {0}")]
+    UnknownTag(u64),
+
+    #[error("Unexpected bytes after the end of the script")]
+    TrailingBytes,
+
+    #[error("The CBOR of the script is not canonical, so its hash would not match its bytes")]
+    NonCanonical,
+
+    #[error("Invalid key hash: {0}")]
+    InvalidKeyHash(#[from] ConversionError),
+}
+
+/// Tag prepended to the CBOR of native scripts when hashing them
+const NATIVE_SCRIPT_HASH_TAG: u8 = 0;
+
+impl NativeScript {
+    /// CBOR encoding of the script, as it appears in witness sets and reference scripts
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut ser = Serializer::new_vec();
+        encode_script(&mut ser, self).expect("Writing to a vector cannot fail");
+        ser.finalize()
+    }
+
+    /// Decode the CBOR encoding of a script
+    ///
+    /// Only the canonical encoding produced by `to_cbor` is accepted: the ledger hashes the
+    /// original bytes, so decoding an indefinite length array or a non-minimal integer would yield
+    /// a script whose `hash` differs from the on-chain one.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, NativeScriptError> {
+        let mut raw = Deserializer::from(Cursor::new(bytes));
+        let script = decode_script(&mut raw)?;
+
+        if raw.inner().position() != bytes.len() as u64 {
+            Err(NativeScriptError::TrailingBytes)
+        } else if script.to_cbor() != bytes {
+            Err(NativeScriptError::NonCanonical)
+        } else {
+            Ok(script)
+        }
+    }
+
+    /// Blake2b-224 hash of the CBOR encoding of the script, prefixed with the native script tag
+    pub fn hash(&self) -> ScriptHash {
+        let mut hash = vec![0; 28];
+        Blake2b::<224>::new()
+            .update(&[NATIVE_SCRIPT_HASH_TAG])
+            .update(&self.to_cbor())
+            .finalize_at(&mut hash);
+
+        ScriptHash(LedgerBytes(hash))
+    }
+
+    pub fn validator_hash(&self) -> ValidatorHash {
+        ValidatorHash(self.hash())
+    }
+
+    pub fn minting_policy_hash(&self) -> MintingPolicyHash {
+        MintingPolicyHash(self.hash())
+    }
+
+    /// Currency symbol of the tokens minted by this script, used as a minting policy
+    pub fn currency_symbol(&self) -> CurrencySymbol {
+        CurrencySymbol::NativeToken(self.minting_policy_hash())
+    }
+
+    /// Address of the script
+    ///
+    /// Without a staking credential this is an enterprise address, otherwise a base or pointer
+    /// address.
+    pub fn address(&self, staking_credential: Option<StakingCredential>) -> Address {
+        Address {
+            credential: Credential::Script(self.validator_hash()),
+            staking_credential,
+        }
+    }
+
+    /// Check whether a transaction satisfies the script, following the ledger rules
+    ///
+    /// `validity_start` and `ttl` are the slots of the validity interval of the transaction, a
+    /// missing slot is an infinite bound. Time locks are only satisfied by finite bounds.
+    pub fn evaluate(
+        &self,
+        signers: &BTreeSet<Ed25519PubKeyHash>,
+        validity_start: Option<&Slot>,
+        ttl: Option<&Slot>,
+    ) -> bool {
+        match self {
+            NativeScript::Sig(key_hash) => signers.contains(key_hash),
+            NativeScript::All(scripts) => scripts
+                .iter()
+                .all(|script| script.evaluate(signers, validity_start, ttl)),
+            NativeScript::Any(scripts) => scripts
+                .iter()
+                .any(|script| script.evaluate(signers, validity_start, ttl)),
+            NativeScript::AtLeast(n, scripts) => {
+                scripts
+                    .iter()
+                    .filter(|script| script.evaluate(signers, validity_start, ttl))
+                    .take(*n as usize)
+                    .count()
+                    == *n as usize
+            }
+            NativeScript::Before(slot) => ttl.is_some_and(|Slot(ttl)| ttl <= &BigInt::from(*slot)),
+            NativeScript::After(slot) => validity_start
+                .is_some_and(|Slot(validity_start)| &BigInt::from(*slot) <= validity_start),
+        }
+    }
+
+    /// Check whether a transaction valid within the time range satisfies the script
+    ///
+    /// The time range is converted to the validity interval of the transaction with
+    /// `SlotConfig::posix_time_range_to_validity`.
+    pub fn evaluate_in_time_range(
+        &self,
+        signers: &BTreeSet<Ed25519PubKeyHash>,
+        range: &POSIXTimeRange,
+        slot_config: &SlotConfig,
+    ) -> Result<bool, ValidityRangeError> {
+        let (validity_start, ttl) = slot_config.posix_time_range_to_validity(range)?;
+
+        Ok(self.evaluate(signers, validity_start.as_ref(), ttl.as_ref()))
+    }
+}
+
+///////////////////
+// CBOR encoding //
+///////////////////
+
+const SIG_TAG: u64 = 0;
+const ALL_TAG: u64 = 1;
+const ANY_TAG: u64 = 2;
+const AT_LEAST_TAG: u64 = 3;
+const AFTER_TAG: u64 = 4;
+const BEFORE_TAG: u64 = 5;
+
+fn encode_script(
+    ser: &mut Serializer<Vec<u8>>,
+    script: &NativeScript,
+) -> Result<(), cbor_event::Error> {
+    match script {
+        NativeScript::Sig(key_hash) => {
+            ser.write_array(Len::Len(2))?
+                .write_unsigned_integer(SIG_TAG)?
+                .write_bytes(&key_hash.0 .0)?;
+        }
+        NativeScript::All(scripts) => {
+            ser.write_array(Len::Len(2))?
+                .write_unsigned_integer(ALL_TAG)?;
+            encode_scripts(ser, scripts)?;
+        }
+        NativeScript::Any(scripts) => {
+            ser.write_array(Len::Len(2))?
+                .write_unsigned_integer(ANY_TAG)?;
+            encode_scripts(ser, scripts)?;
+        }
+        NativeScript::AtLeast(n, scripts) => {
+            ser.write_array(Len::Len(3))?
+                .write_unsigned_integer(AT_LEAST_TAG)?
+                .write_unsigned_integer((*n).into())?;
+            encode_scripts(ser, scripts)?;
+        }
+        NativeScript::After(slot) => {
+            ser.write_array(Len::Len(2))?
+                .write_unsigned_integer(AFTER_TAG)?
+                .write_unsigned_integer(*slot)?;
+        }
+        NativeScript::Before(slot) => {
+            ser.write_array(Len::Len(2))?
+                .write_unsigned_integer(BEFORE_TAG)?
+                .write_unsigned_integer(*slot)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_scripts(
+    ser: &mut Serializer<Vec<u8>>,
+    scripts: &[NativeScript],
+) -> Result<(), cbor_event::Error> {
+    ser.write_array(Len::Len(scripts.len() as u64))?;
+
+    scripts
+        .iter()
+        .try_for_each(|script| encode_script(ser, script))
+}
+
+fn decode_script(raw: &mut Deserializer<Cursor<&[u8]>>) -> Result<NativeScript, NativeScriptError> {
+    let len = raw.array()?;
+    let tag = raw.unsigned_integer()?;
+
+    let expected = if tag == AT_LEAST_TAG { 3 } else { 2 };
+    if len != Len::Len(expected) {
+        return Err(NativeScriptError::InvalidArrayLength { expected, got: len });
+    }
+
+    Ok(match tag {
+        SIG_TAG => NativeScript::Sig(Ed25519PubKeyHash::from_bytes(raw.bytes()?)?),
+        ALL_TAG => NativeScript::All(decode_scripts(raw)?),
+        ANY_TAG => NativeScript::Any(decode_scripts(raw)?),
+        AT_LEAST_TAG => {
+            let n = raw.unsigned_integer()?;
+            let n = u32::try_from(n).map_err(|_| cbor_event::Error::ExpectedU32)?;
+            NativeScript::AtLeast(n, decode_scripts(raw)?)
+        }
+        AFTER_TAG => NativeScript::After(raw.unsigned_integer()?),
+        BEFORE_TAG => NativeScript::Before(raw.unsigned_integer()?),
+        _ => Err(NativeScriptError::UnknownTag(tag))?,
+    })
+}
+
+/// Decode a definite or indefinite length array of scripts
+fn decode_scripts(
+    raw: &mut Deserializer<Cursor<&[u8]>>,
+) -> Result<Vec<NativeScript>, NativeScriptError> {
+    let len = raw.array()?;
+    let mut scripts = Vec::new();
+
+    loop {
+        match len {
+            Len::Len(len) if scripts.len() as u64 == len => break,
+            Len::Indefinite if raw.cbor_type()? == Type::Special && raw.special_break()? => break,
+            _ => scripts.push(decode_script(raw)?),
+        }
+    }
+
+    Ok(scripts)
+}
+
+/////////////////////
+// CSL conversions //
+/////////////////////
+
+impl FromCSL<csl::NativeScript> for NativeScript {
+    fn from_csl(value: &csl::NativeScript) -> Self {
+        match value.kind() {
+            csl::NativeScriptKind::ScriptPubkey => NativeScript::Sig(Ed25519PubKeyHash::from_csl(
+                &value.as_script_pubkey().unwrap().addr_keyhash(),
+            )),
+            csl::NativeScriptKind::ScriptAll => NativeScript::All(Vec::from_csl(
+                &value.as_script_all().unwrap().native_scripts(),
+            )),
+            csl::NativeScriptKind::ScriptAny => NativeScript::Any(Vec::from_csl(
+                &value.as_script_any().unwrap().native_scripts(),
+            )),
+            csl::NativeScriptKind::ScriptNOfK => {
+                let script = value.as_script_n_of_k().unwrap();
+                NativeScript::AtLeast(script.n(), Vec::from_csl(&script.native_scripts()))
+            }
+            csl::NativeScriptKind::TimelockStart => {
+                NativeScript::After(value.as_timelock_start().unwrap().slot_bignum().into())
+            }
+            csl::NativeScriptKind::TimelockExpiry => {
+                NativeScript::Before(value.as_timelock_expiry().unwrap().slot_bignum().into())
+            }
+        }
+    }
+}
+
+impl FromCSL<csl::NativeScripts> for Vec<NativeScript> {
+    fn from_csl(value: &csl::NativeScripts) -> Self {
+        (0..value.len())
+            .map(|idx| NativeScript::from_csl(&value.get(idx)))
+            .collect()
+    }
+}
+
+impl TryFromPLA<NativeScript> for csl::NativeScript {
+    fn try_from_pla(val: &NativeScript) -> Result<Self, TryFromPLAError> {
+        Ok(match val {
+            NativeScript::Sig(key_hash) => csl::NativeScript::new_script_pubkey(
+                &csl::ScriptPubkey::new(&key_hash.try_to_csl()?),
+            ),
+            NativeScript::All(scripts) => {
+                csl::NativeScript::new_script_all(&csl::ScriptAll::new(&scripts.try_to_csl()?))
+            }
+            NativeScript::Any(scripts) => {
+                csl::NativeScript::new_script_any(&csl::ScriptAny::new(&scripts.try_to_csl()?))
+            }
+            NativeScript::AtLeast(n, scripts) => csl::NativeScript::new_script_n_of_k(
+                &csl::ScriptNOfK::new(*n, &scripts.try_to_csl()?),
+            ),
+            NativeScript::After(slot) => csl::NativeScript::new_timelock_start(
+                &csl::TimelockStart::new_timelockstart(&csl::BigNum::from(*slot)),
+            ),
+            NativeScript::Before(slot) => csl::NativeScript::new_timelock_expiry(
+                &csl::TimelockExpiry::new_timelockexpiry(&csl::BigNum::from(*slot)),
+            ),
+        })
+    }
+}
+
+impl TryFromPLA<Vec<NativeScript>> for csl::NativeScripts {
+    fn try_from_pla(val: &Vec<NativeScript>) -> Result<Self, TryFromPLAError> {
+        val.iter()
+            .try_fold(csl::NativeScripts::new(), |mut acc, script| {
+                acc.add(&script.try_to_csl()?);
+                Ok(acc)
+            })
+    }
+}
+
+//////////
+// JSON //
+//////////
+
+/// Native script in the JSON format of cardano-cli
+#[cfg(feature = "serde")]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum NativeScriptJson {
+    Sig {
+        #[serde(rename = "keyHash")]
+        key_hash: String,
+    },
+    All {
+        scripts: Vec<NativeScript>,
+    },
+    Any {
+        scripts: Vec<NativeScript>,
+    },
+    AtLeast {
+        required: u32,
+        scripts: Vec<NativeScript>,
+    },
+    Before {
+        slot: u64,
+    },
+    After {
+        slot: u64,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<NativeScript> for NativeScriptJson {
+    fn from(script: NativeScript) -> Self {
+        match script {
+            NativeScript::Sig(key_hash) => NativeScriptJson::Sig {
+                key_hash: HEXLOWER.encode(&key_hash.0 .0),
+            },
+            NativeScript::All(scripts) => NativeScriptJson::All { scripts },
+            NativeScript::Any(scripts) => NativeScriptJson::Any { scripts },
+            NativeScript::AtLeast(required, scripts) => {
+                NativeScriptJson::AtLeast { required, scripts }
+            }
+            NativeScript::Before(slot) => NativeScriptJson::Before { slot },
+            NativeScript::After(slot) => NativeScriptJson::After { slot },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NativeScriptJson> for NativeScript {
+    type Error = NativeScriptError;
+
+    fn try_from(script: NativeScriptJson) -> Result<Self, Self::Error> {
+        Ok(match script {
+            NativeScriptJson::Sig { key_hash } => {
+                let bytes = HEXLOWER_PERMISSIVE
+                    .decode(key_hash.as_bytes())
+                    .map_err(|err| ConversionError::hex_decode_error(err, &key_hash))?;
+
+                NativeScript::Sig(Ed25519PubKeyHash::from_bytes(bytes)?)
+            }
+            NativeScriptJson::All { scripts } => NativeScript::All(scripts),
+            NativeScriptJson::Any { scripts } => NativeScript::Any(scripts),
+            NativeScriptJson::AtLeast { required, scripts } => {
+                NativeScript::AtLeast(required, scripts)
+            }
+            NativeScriptJson::Before { slot } => NativeScript::Before(slot),
+            NativeScriptJson::After { slot } => NativeScript::After(slot),
+        })
+    }
+}
@@ -0,0 +1,113 @@
+//! Cardano networks, with the parameters needed to encode and validate addresses
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::slot_config::SlotConfig;
+use crate::v1::address::AddressError;
+
+/////////////
+// Network //
+/////////////
+
+/// Protocol magic of the mainnet, which Byron addresses of the mainnet don't include
+pub const MAINNET_PROTOCOL_MAGIC: u32 = 764824073;
+
+/// A Cardano network
+///
+/// Use the presets, or `Network::new` for a custom network (e.g. a local devnet). `Network::new`
+/// uses the CIP-5 bech32 prefixes, which only depend on whether the network id is the mainnet
+/// one; use `with_prefixes` to override them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Network {
+    /// Network id of Shelley addresses (the network tag of their header)
+    pub network_id: u8,
+    /// Protocol magic, used in the handshake of nodes and in Byron addresses
+    pub protocol_magic: u32,
+    /// Bech32 prefix of payment addresses
+    pub address_prefix: String,
+    /// Bech32 prefix of reward addresses
+    pub reward_address_prefix: String,
+    pub slot_config: SlotConfig,
+}
+
+impl Network {
+    pub fn new(network_id: u8, protocol_magic: u32, slot_config: SlotConfig) -> Self {
+        let suffix = if network_id == 1 { "" } else { "_test" };
+
+        Network {
+            network_id,
+            protocol_magic,
+            address_prefix: format!("addr{}", suffix),
+            reward_address_prefix: format!("stake{}", suffix),
+            slot_config,
+        }
+    }
+
+    /// Network with custom bech32 prefixes for payment and reward addresses
+    pub fn with_prefixes(self, address_prefix: &str, reward_address_prefix: &str) -> Self {
+        Network {
+            address_prefix: address_prefix.to_string(),
+            reward_address_prefix: reward_address_prefix.to_string(),
+            ..self
+        }
+    }
+
+    /// Network of a network id read from an address header
+    ///
+    /// Testnets share their network id, so any network id other than the mainnet one gives a
+    /// network with the parameters of preprod. Use `from_bech32_with_network` functions to decode
+    /// addresses of a known network instead.
+    pub fn from_network_id(network_id: u8) -> Self {
+        if network_id == 1 {
+            Self::mainnet()
+        } else {
+            Network {
+                network_id,
+                ..Self::preprod()
+            }
+        }
+    }
+
+    pub fn mainnet() -> Self {
+        Self::new(1, MAINNET_PROTOCOL_MAGIC, SlotConfig::mainnet())
+    }
+
+    pub fn preprod() -> Self {
+        Self::new(0, 1, SlotConfig::preprod())
+    }
+
+    pub fn preview() -> Self {
+        Self::new(0, 2, SlotConfig::preview())
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        self.network_id == 1
+    }
+
+    /// Check that the network tag of a Shelley address belongs to this network
+    pub fn check_network_tag(&self, network_tag: u8) -> Result<(), AddressError> {
+        if network_tag == self.network_id {
+            Ok(())
+        } else {
+            Err(AddressError::NetworkMismatch {
+                expected: self.network_id,
+                got: network_tag,
+            })
+        }
+    }
+
+    /// Check the network magic attribute of a Byron address, which is omitted on the mainnet
+    pub fn check_network_magic(&self, network_magic: Option<u32>) -> Result<(), AddressError> {
+        let network_magic = network_magic.unwrap_or(MAINNET_PROTOCOL_MAGIC);
+
+        if network_magic == self.protocol_magic {
+            Ok(())
+        } else {
+            Err(AddressError::ProtocolMagicMismatch {
+                expected: self.protocol_magic,
+                got: network_magic,
+            })
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Conversion between slots and POSIX times, and between transaction validity intervals and
+//! `POSIXTimeRange`
+use cardano_serialization_lib as csl;
+use num_bigint::BigInt;
+use num_traits::Signed;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csl::csl_to_pla::FromCSL;
+use crate::csl::pla_to_csl::{TryFromPLAError, TryToCSL};
+use crate::v1::address::Slot;
+use crate::v1::interval::{Extended, LowerBound, PlutusInterval, UpperBound};
+use crate::v1::transaction::{POSIXTime, POSIXTimeRange};
+
+////////////////
+// SlotConfig //
+////////////////
+
+/// Summary of an era with a fixed slot length, starting at `zero_slot` and `zero_time`
+///
+/// The presets describe the networks since the Shelley hard fork, so they cannot be used to
+/// convert Byron era slots.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SlotConfig {
+    /// POSIX time at the start of `zero_slot`
+    pub zero_time: POSIXTime,
+    pub zero_slot: Slot,
+    /// Length of a slot in milliseconds
+    pub slot_length: u64,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ValidityRangeError {
+    #[error("Lower bound of a validity range cannot be positive infinity")]
+    PosInfLowerBound,
+
+    #[error("Upper bound of a validity range cannot be negative infinity")]
+    NegInfUpperBound,
+
+    #[error(transparent)]
+    TryFromPLAError(#[from] TryFromPLAError),
+}
+
+impl SlotConfig {
+    pub fn new(zero_time: POSIXTime, zero_slot: Slot, slot_length: u64) -> Self {
+        SlotConfig {
+            zero_time,
+            zero_slot,
+            slot_length,
+        }
+    }
+
+    pub fn mainnet() -> Self {
+        Self::new(
+            POSIXTime(BigInt::from(1596059091000u64)),
+            Slot(BigInt::from(4492800)),
+            1000,
+        )
+    }
+
+    pub fn preprod() -> Self {
+        Self::new(
+            POSIXTime(BigInt::from(1655769600000u64)),
+            Slot(BigInt::from(86400)),
+            1000,
+        )
+    }
+
+    pub fn preview() -> Self {
+        Self::new(
+            POSIXTime(BigInt::from(1666656000000u64)),
+            Slot(BigInt::from(0)),
+            1000,
+        )
+    }
+
+    /// POSIX time at the start of the slot
+    pub fn slot_to_posix_time(&self, slot: &Slot) -> POSIXTime {
+        POSIXTime(&self.zero_time.0 + (&slot.0 - &self.zero_slot.0) * self.slot_length)
+    }
+
+    /// Slot containing the POSIX time
+    pub fn posix_time_to_slot(&self, time: &POSIXTime) -> Slot {
+        Slot(div_floor(&time.0 - &self.zero_time.0, self.slot_length) + &self.zero_slot.0)
+    }
+
+    /// First slot starting at or after the POSIX time
+    fn posix_time_to_slot_ceil(&self, time: &POSIXTime) -> Slot {
+        Slot(-div_floor(&self.zero_time.0 - &time.0, self.slot_length) + &self.zero_slot.0)
+    }
+
+    /// Time range of a transaction, as seen by scripts in `TxInfo.valid_range`
+    ///
+    /// Following the ledger, the validity start slot is an inclusive lower bound, and the TTL slot
+    /// is an exclusive upper bound. Missing slots are infinite bounds.
+    pub fn validity_to_posix_time_range(
+        &self,
+        validity_start: Option<&Slot>,
+        ttl: Option<&Slot>,
+    ) -> POSIXTimeRange {
+        PlutusInterval {
+            from: LowerBound {
+                bound: validity_start.map_or(Extended::NegInf, |slot| {
+                    Extended::Finite(self.slot_to_posix_time(slot))
+                }),
+                closed: true,
+            },
+            to: UpperBound {
+                bound: ttl.map_or(Extended::PosInf, |slot| {
+                    Extended::Finite(self.slot_to_posix_time(slot))
+                }),
+                closed: ttl.is_none(),
+            },
+        }
+    }
+
+    /// Validity start and TTL slots of a transaction, which is only valid within the time range
+    ///
+    /// The slots are rounded inwards, so every POSIX time of the resulting `TxInfo.valid_range` is
+    /// also in `range`. As POSIX times are measured in milliseconds, a closed bound `t` is
+    /// equivalent to an open bound at the next or previous millisecond.
+    pub fn posix_time_range_to_validity(
+        &self,
+        range: &POSIXTimeRange,
+    ) -> Result<(Option<Slot>, Option<Slot>), ValidityRangeError> {
+        let validity_start = match &range.from.bound {
+            Extended::NegInf => None,
+            Extended::Finite(time) if range.from.closed => Some(self.posix_time_to_slot_ceil(time)),
+            Extended::Finite(time) => Some(Slot(self.posix_time_to_slot(time).0 + 1)),
+            Extended::PosInf => Err(ValidityRangeError::PosInfLowerBound)?,
+        };
+
+        let ttl = match &range.to.bound {
+            Extended::PosInf => None,
+            Extended::Finite(time) if range.to.closed => {
+                Some(self.posix_time_to_slot(&POSIXTime(&time.0 + 1)))
+            }
+            Extended::Finite(time) => Some(self.posix_time_to_slot(time)),
+            Extended::NegInf => Err(ValidityRangeError::NegInfUpperBound)?,
+        };
+
+        Ok((validity_start, ttl))
+    }
+
+    /// Time range of a CSL transaction body, as seen by scripts in `TxInfo.valid_range`
+    pub fn tx_body_valid_range(&self, tx_body: &csl::TransactionBody) -> POSIXTimeRange {
+        self.validity_to_posix_time_range(
+            tx_body
+                .validity_start_interval_bignum()
+                .map(|slot| Slot::from_csl(&slot))
+                .as_ref(),
+            tx_body
+                .ttl_bignum()
+                .map(|slot| Slot::from_csl(&slot))
+                .as_ref(),
+        )
+    }
+
+    /// Set the validity start and TTL of a CSL transaction body, so it is only valid within the
+    /// time range
+    ///
+    /// CSL cannot remove a validity start, so an existing one is kept if the range has no lower
+    /// bound.
+    pub fn set_tx_body_valid_range(
+        &self,
+        tx_body: &mut csl::TransactionBody,
+        range: &POSIXTimeRange,
+    ) -> Result<(), ValidityRangeError> {
+        let (validity_start, ttl) = self.posix_time_range_to_validity(range)?;
+
+        if let Some(validity_start) = validity_start {
+            tx_body.set_validity_start_interval_bignum(&validity_start.try_to_csl()?);
+        }
+
+        match ttl {
+            Some(ttl) => tx_body.set_ttl(&ttl.try_to_csl()?),
+            None => tx_body.remove_ttl(),
+        }
+
+        Ok(())
+    }
+}
+
+/// Integer division rounding towards negative infinity
+fn div_floor(numerator: BigInt, denominator: u64) -> BigInt {
+    let denominator = BigInt::from(denominator);
+    let quotient = &numerator / &denominator;
+
+    if numerator.is_negative() && quotient.clone() * &denominator != numerator {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
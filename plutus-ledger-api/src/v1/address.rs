@@ -1,23 +1,27 @@
 //! Types related to Cardano addresses
 use std::str::FromStr;
 
-use anyhow::anyhow;
+use bech32::{FromBase32, ToBase32};
 use cardano_serialization_lib as csl;
 
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::{self, Error, Json};
-use num_bigint::BigInt;
+use num_bigint::{BigInt, BigUint};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate as plutus_ledger_api;
+use crate::byron::{ByronAddress, ByronAddressError};
 use crate::csl::csl_to_pla::{FromCSL, TryFromCSL, TryFromCSLError, TryToPLA};
 use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
+use crate::network::Network;
 use crate::plutus_data::{
     parse_constr, parse_fixed_len_constr_fields, IsPlutusData, PlutusData, PlutusDataError,
 };
 use crate::v1::crypto::Ed25519PubKeyHash;
-use crate::v1::script::ValidatorHash;
+use crate::v1::crypto::LedgerBytes;
+use crate::v1::interval::Discrete;
+use crate::v1::script::{ScriptHash, ValidatorHash};
 
 /////////////
 // Address //
@@ -38,23 +42,100 @@ pub struct Address {
 }
 
 impl Address {
-    pub fn with_extra_info(&self, network_tag: u8) -> AddressWithExtraInfo {
+    pub fn with_extra_info<'a>(&'a self, network: &'a Network) -> AddressWithExtraInfo<'a> {
         AddressWithExtraInfo {
             address: self,
-            network_tag,
+            network,
         }
     }
 }
 
+impl Address {
+    /// Decode an address from the binary format of CIP-19, returning it with its network tag
+    ///
+    /// Only Shelley base, pointer and enterprise addresses are supported.
+    pub fn from_bytes(bytes: &[u8]) -> Result<(Self, u8), AddressError> {
+        let (address_type, network_tag, payload) = decode_header(bytes)?;
+
+        let address = match address_type {
+            0b0000..=0b0011 => {
+                check_length(address_type, payload, 2 * CREDENTIAL_LENGTH)?;
+                let (payment, stake) = payload.split_at(CREDENTIAL_LENGTH);
+
+                Address {
+                    credential: decode_credential(address_type & 0b0001 != 0, payment),
+                    staking_credential: Some(StakingCredential::Hash(decode_credential(
+                        address_type & 0b0010 != 0,
+                        stake,
+                    ))),
+                }
+            }
+            0b0100 | 0b0101 => {
+                if payload.len() < CREDENTIAL_LENGTH {
+                    return Err(AddressError::InvalidLength {
+                        address_type,
+                        expected: 1 + CREDENTIAL_LENGTH + 3,
+                        got: bytes.len(),
+                    });
+                }
+                let (payment, pointer) = payload.split_at(CREDENTIAL_LENGTH);
+
+                Address {
+                    credential: decode_credential(address_type & 0b0001 != 0, payment),
+                    staking_credential: Some(StakingCredential::Pointer(decode_chain_pointer(
+                        address_type,
+                        bytes.len(),
+                        pointer,
+                    )?)),
+                }
+            }
+            0b0110 | 0b0111 => {
+                check_length(address_type, payload, CREDENTIAL_LENGTH)?;
+
+                Address {
+                    credential: decode_credential(address_type & 0b0001 != 0, payload),
+                    staking_credential: None,
+                }
+            }
+            0b1110 | 0b1111 => Err(AddressError::UnexpectedAddressType {
+                expected: String::from("payment address"),
+                got: address_type,
+            })?,
+            _ => Err(AddressError::UnsupportedAddressType(address_type))?,
+        };
+
+        Ok((address, network_tag))
+    }
+
+    /// Decode a bech32 address with the `addr` (mainnet) or `addr_test` (testnets) prefix,
+    /// returning it with its network tag
+    pub fn from_bech32(s: &str) -> Result<(Self, u8), AddressError> {
+        let (prefix, bytes) = decode_bech32(s)?;
+        let (address, network_tag) = Self::from_bytes(&bytes)?;
+
+        check_prefix(&prefix, network_prefix("addr", network_tag))?;
+
+        Ok((address, network_tag))
+    }
+
+    /// Decode a bech32 address, checking that it belongs to the network and has its prefix
+    pub fn from_bech32_with_network(s: &str, network: &Network) -> Result<Self, AddressError> {
+        let (prefix, bytes) = decode_bech32(s)?;
+        let (address, network_tag) = Self::from_bytes(&bytes)?;
+
+        network.check_network_tag(network_tag)?;
+        check_prefix(&prefix, network.address_prefix.clone())?;
+
+        Ok(address)
+    }
+}
+
+/// Parse a bech32 address, discarding its network tag
 impl FromStr for Address {
-    type Err = anyhow::Error;
+    type Err = AddressError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let csl_addr = csl::Address::from_bech32(s)
-            .map_err(|err| anyhow!("Couldn't parse bech32 address: {}", err))?;
-        csl_addr
-            .try_to_pla()
-            .map_err(|err| anyhow!("Couldn't convert address: {}", err))
+        Self::from_bech32(s).map(|(address, _)| address)
     }
 }
 
@@ -75,6 +156,11 @@ impl TryFromCSL<csl::Address> for Address {
                 credential: Credential::from_csl(&addr.payment_cred()),
                 staking_credential: None,
             })
+        } else if let Some(addr) = csl::ByronAddress::from_address(value) {
+            Err(TryFromCSLError::ImpossibleConversion(format!(
+                "Byron address {} cannot be represented as a Plutus address, use LedgerAddress instead",
+                addr.to_base58()
+            )))
         } else {
             Err(TryFromCSLError::ImpossibleConversion(format!(
                 "Unable to represent address {:?}",
@@ -89,38 +175,71 @@ impl TryFromCSL<csl::Address> for Address {
 /// a bech32 address format.
 pub struct AddressWithExtraInfo<'a> {
     pub address: &'a Address,
-    pub network_tag: u8,
+    pub network: &'a Network,
 }
 
 impl TryFromPLA<AddressWithExtraInfo<'_>> for csl::Address {
     fn try_from_pla(val: &AddressWithExtraInfo<'_>) -> Result<Self, TryFromPLAError> {
         let payment = val.address.credential.try_to_csl()?;
+        let network_id = val.network.network_id;
 
         Ok(match val.address.staking_credential {
-            None => csl::EnterpriseAddress::new(val.network_tag, &payment).to_address(),
+            None => csl::EnterpriseAddress::new(network_id, &payment).to_address(),
             Some(ref sc) => match sc {
                 StakingCredential::Hash(c) => {
-                    csl::BaseAddress::new(val.network_tag, &payment, &c.try_to_csl()?).to_address()
+                    csl::BaseAddress::new(network_id, &payment, &c.try_to_csl()?).to_address()
                 }
                 StakingCredential::Pointer(ptr) => {
-                    csl::PointerAddress::new(val.network_tag, &payment, &ptr.try_to_csl()?)
-                        .to_address()
+                    csl::PointerAddress::new(network_id, &payment, &ptr.try_to_csl()?).to_address()
                 }
             },
         })
     }
 }
 
+impl AddressWithExtraInfo<'_> {
+    /// Encode the address in the binary format of CIP-19
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AddressError> {
+        let payment = encode_credential(&self.address.credential)?;
+        let payment_bit = is_script(&self.address.credential) as u8;
+
+        let (address_type, mut payload) = match &self.address.staking_credential {
+            Some(StakingCredential::Hash(stake)) => (
+                payment_bit | (is_script(stake) as u8) << 1,
+                [payment, encode_credential(stake)?].concat(),
+            ),
+            Some(StakingCredential::Pointer(pointer)) => {
+                let mut payload = payment.to_vec();
+                encode_chain_pointer(pointer, &mut payload)?;
+
+                (0b0100 | payment_bit, payload)
+            }
+            None => (0b0110 | payment_bit, payment.to_vec()),
+        };
+
+        payload.insert(0, encode_header(address_type, self.network.network_id)?);
+
+        Ok(payload)
+    }
+
+    /// Encode the address in bech32, with the address prefix of the network
+    pub fn to_bech32(&self) -> Result<String, AddressError> {
+        Ok(bech32::encode(
+            &self.network.address_prefix,
+            self.to_bytes()?.to_base32(),
+        )?)
+    }
+}
+
 /// Serializing into a bech32 address format.
+///
+/// Addresses that cannot be encoded (see `AddressWithExtraInfo::to_bech32`) are displayed as
+/// `INVALID ADDRESS`, followed by the reason.
 impl std::fmt::Display for AddressWithExtraInfo<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let bech32_addr: Option<String> = self
-            .try_to_csl()
-            .ok()
-            .and_then(|csl_addr: csl::Address| csl_addr.to_bech32(None).ok());
-        match bech32_addr {
-            Some(addr) => write!(f, "{}", addr),
-            None => write!(f, "INVALID ADDRESS {:?}", self),
+        match self.to_bech32() {
+            Ok(addr) => write!(f, "{}", addr),
+            Err(err) => write!(f, "INVALID ADDRESS {:?}: {}", self, err),
         }
     }
 }
@@ -218,6 +337,16 @@ pub enum StakingCredential {
 }
 
 // NOTE(chfanghr): ChainPointer doesn't have a IsPlutusData instance so derive doesn't work here.
+impl StakingCredential {
+    /// Reward address of the staking credential on the network
+    pub fn with_network<'a>(&'a self, network: &'a Network) -> RewardAddressWithExtraInfo<'a> {
+        RewardAddressWithExtraInfo {
+            staking_credential: self,
+            network,
+        }
+    }
+}
+
 impl IsPlutusData for StakingCredential {
     fn to_plutus_data(&self) -> PlutusData {
         match self {
@@ -330,21 +459,351 @@ impl FromCSL<csl::Pointer> for StakingCredential {
     }
 }
 
+///////////////////
+// RewardAddress //
+///////////////////
+
+/// Reward (stake) address, holding the rewards of a staking credential on a network
+///
+/// Chain pointers cannot be used in reward addresses, so only credential hashes are allowed.
+/// Like `Address`, only the network tag of the header is kept: the bech32 prefix of a network
+/// is supplied when encoding, with `to_bech32`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RewardAddress {
+    /// Network tag of the header (the network id of the network)
+    pub network_tag: u8,
+    pub credential: Credential,
+}
+
+impl RewardAddress {
+    /// Decode a reward address from the binary format of CIP-19
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AddressError> {
+        let (address_type, network_tag, payload) = decode_header(bytes)?;
+
+        match address_type {
+            0b1110 | 0b1111 => {
+                check_length(address_type, payload, CREDENTIAL_LENGTH)?;
+
+                Ok(RewardAddress {
+                    network_tag,
+                    credential: decode_credential(address_type & 0b0001 != 0, payload),
+                })
+            }
+            0b0000..=0b0111 => Err(AddressError::UnexpectedAddressType {
+                expected: String::from("reward address"),
+                got: address_type,
+            }),
+            _ => Err(AddressError::UnsupportedAddressType(address_type)),
+        }
+    }
+
+    /// Decode a bech32 reward address with the `stake` (mainnet) or `stake_test` (testnets)
+    /// prefix
+    pub fn from_bech32(s: &str) -> Result<Self, AddressError> {
+        let (prefix, bytes) = decode_bech32(s)?;
+        let reward_address = Self::from_bytes(&bytes)?;
+
+        check_prefix(&prefix, network_prefix("stake", reward_address.network_tag))?;
+
+        Ok(reward_address)
+    }
+
+    /// Decode a bech32 reward address, checking that it belongs to the network and has its
+    /// prefix
+    pub fn from_bech32_with_network(s: &str, network: &Network) -> Result<Self, AddressError> {
+        let (prefix, bytes) = decode_bech32(s)?;
+        let reward_address = Self::from_bytes(&bytes)?;
+
+        network.check_network_tag(reward_address.network_tag)?;
+        check_prefix(&prefix, network.reward_address_prefix.clone())?;
+
+        Ok(reward_address)
+    }
+
+    /// Encode the reward address in the binary format of CIP-19
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AddressError> {
+        encode_reward_address(&self.credential, self.network_tag)
+    }
+
+    /// Encode the reward address in bech32, with the reward address prefix of the network,
+    /// checking that the address belongs to it
+    pub fn to_bech32(&self, network: &Network) -> Result<String, AddressError> {
+        network.check_network_tag(self.network_tag)?;
+
+        Ok(bech32::encode(
+            &network.reward_address_prefix,
+            self.to_bytes()?.to_base32(),
+        )?)
+    }
+}
+
+impl Address {
+    /// Reward address of the staking part of the address on the network, if it has a staking
+    /// credential hash (pointer addresses have no reward address)
+    pub fn reward_address(&self, network: &Network) -> Option<RewardAddress> {
+        match &self.staking_credential {
+            Some(StakingCredential::Hash(credential)) => Some(RewardAddress {
+                network_tag: network.network_id,
+                credential: credential.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<RewardAddress> for StakingCredential {
+    fn from(reward_address: RewardAddress) -> Self {
+        StakingCredential::Hash(reward_address.credential)
+    }
+}
+
+impl FromStr for RewardAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bech32(s)
+    }
+}
+
+/// Serializing into a bech32 address format with the CIP-5 prefix of the network tag (`stake`
+/// for mainnet and `stake_test` for testnets). Use `RewardAddress::to_bech32` for custom prefixes.
+///
+/// Reward addresses that cannot be encoded (see `RewardAddress::to_bytes`) are displayed as
+/// `INVALID ADDRESS`, followed by the reason.
+impl std::fmt::Display for RewardAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bech32 = self.to_bytes().and_then(|bytes| {
+            Ok(bech32::encode(
+                &network_prefix("stake", self.network_tag),
+                bytes.to_base32(),
+            )?)
+        });
+
+        match bech32 {
+            Ok(addr) => write!(f, "{}", addr),
+            Err(err) => write!(f, "INVALID ADDRESS {:?}: {}", self, err),
+        }
+    }
+}
+
+impl TryFromCSL<csl::RewardAddress> for RewardAddress {
+    fn try_from_csl(value: &csl::RewardAddress) -> Result<Self, TryFromCSLError> {
+        Ok(RewardAddress {
+            network_tag: value.network_id(),
+            credential: Credential::from_csl(&value.payment_cred()),
+        })
+    }
+}
+
+impl TryFromCSL<csl::Address> for RewardAddress {
+    fn try_from_csl(value: &csl::Address) -> Result<Self, TryFromCSLError> {
+        csl::RewardAddress::from_address(value)
+            .ok_or_else(|| {
+                TryFromCSLError::ImpossibleConversion(format!(
+                    "Address {:?} is not a reward address",
+                    value
+                ))
+            })?
+            .try_to_pla()
+    }
+}
+
+impl TryFromPLA<RewardAddress> for csl::RewardAddress {
+    fn try_from_pla(val: &RewardAddress) -> Result<Self, TryFromPLAError> {
+        Ok(csl::RewardAddress::new(
+            val.network_tag,
+            &val.credential.try_to_csl()?,
+        ))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RewardAddressWithExtraInfo<'a> {
     pub staking_credential: &'a StakingCredential,
-    pub network_tag: u8,
+    pub network: &'a Network,
+}
+
+impl RewardAddressWithExtraInfo<'_> {
+    /// Encode the reward address in the binary format of CIP-19
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AddressError> {
+        match self.staking_credential {
+            StakingCredential::Hash(credential) => {
+                encode_reward_address(credential, self.network.network_id)
+            }
+            StakingCredential::Pointer(_) => Err(AddressError::PointerRewardAddress),
+        }
+    }
+
+    /// Encode the reward address in bech32, with the reward address prefix of the network
+    pub fn to_bech32(&self) -> Result<String, AddressError> {
+        Ok(bech32::encode(
+            &self.network.reward_address_prefix,
+            self.to_bytes()?.to_base32(),
+        )?)
+    }
+}
+
+/// Serializing into a bech32 address format.
+///
+/// Reward addresses that cannot be encoded (see `RewardAddressWithExtraInfo::to_bech32`) are
+/// displayed as `INVALID ADDRESS`, followed by the reason.
+impl std::fmt::Display for RewardAddressWithExtraInfo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_bech32() {
+            Ok(addr) => write!(f, "{}", addr),
+            Err(err) => write!(f, "INVALID ADDRESS {:?}: {}", self, err),
+        }
+    }
 }
 
 impl TryFromPLA<RewardAddressWithExtraInfo<'_>> for csl::RewardAddress {
     fn try_from_pla(val: &RewardAddressWithExtraInfo<'_>) -> Result<Self, TryFromPLAError> {
         Ok(csl::RewardAddress::new(
-            val.network_tag,
+            val.network.network_id,
             &val.staking_credential.try_to_csl()?,
         ))
     }
 }
 
+///////////////////
+// LedgerAddress //
+///////////////////
+
+/// Address of a transaction output in the ledger, which can be a Byron bootstrap address
+///
+/// Byron addresses cannot be represented in Plutus, so converting a `LedgerAddress` into an
+/// `Address` fails with `AddressError::ByronAddress` for them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LedgerAddress {
+    Shelley(Address),
+    Byron(ByronAddress),
+}
+
+impl LedgerAddress {
+    pub fn with_extra_info<'a>(&'a self, network: &'a Network) -> LedgerAddressWithExtraInfo<'a> {
+        LedgerAddressWithExtraInfo {
+            address: self,
+            network,
+        }
+    }
+
+    /// Parse a bech32 Shelley address, or a base58 Byron address, checking that it belongs to
+    /// the network
+    ///
+    /// Byron addresses are checked against the protocol magic of the network.
+    pub fn from_str_with_network(s: &str, network: &Network) -> Result<Self, AddressError> {
+        match Address::from_bech32_with_network(s, network) {
+            Ok(address) => Ok(address.into()),
+            Err(AddressError::Bech32(_)) => {
+                let address = ByronAddress::from_str(s)?;
+
+                network.check_network_magic(address.network_magic())?;
+
+                Ok(address.into())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decode an address from its binary format, returning it with its network tag
+    ///
+    /// Byron addresses don't have a network tag in their header, and are returned with the
+    /// network tag 1 for mainnet, or 0 if they have a network magic attribute.
+    pub fn from_bytes(bytes: &[u8]) -> Result<(Self, u8), AddressError> {
+        let (address_type, ..) = decode_header(bytes)?;
+
+        if address_type == BYRON_ADDRESS_TYPE {
+            let address = ByronAddress::from_bytes(bytes)?;
+            let network_tag = if address.network_magic().is_some() {
+                0
+            } else {
+                1
+            };
+
+            Ok((LedgerAddress::Byron(address), network_tag))
+        } else {
+            Address::from_bytes(bytes).map(|(address, network_tag)| (address.into(), network_tag))
+        }
+    }
+}
+
+impl From<Address> for LedgerAddress {
+    fn from(address: Address) -> Self {
+        LedgerAddress::Shelley(address)
+    }
+}
+
+impl From<ByronAddress> for LedgerAddress {
+    fn from(address: ByronAddress) -> Self {
+        LedgerAddress::Byron(address)
+    }
+}
+
+impl TryFrom<LedgerAddress> for Address {
+    type Error = AddressError;
+
+    fn try_from(address: LedgerAddress) -> Result<Self, Self::Error> {
+        match address {
+            LedgerAddress::Shelley(address) => Ok(address),
+            LedgerAddress::Byron(address) => Err(AddressError::ByronAddress(address)),
+        }
+    }
+}
+
+/// Parse a bech32 Shelley address, or a base58 Byron address, discarding its network tag
+impl FromStr for LedgerAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match Address::from_str(s) {
+            Ok(address) => Ok(address.into()),
+            Err(AddressError::Bech32(_)) => Ok(ByronAddress::from_str(s)?.into()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl TryFromCSL<csl::Address> for LedgerAddress {
+    fn try_from_csl(value: &csl::Address) -> Result<Self, TryFromCSLError> {
+        match csl::ByronAddress::from_address(value) {
+            Some(addr) => Ok(LedgerAddress::Byron(addr.try_to_pla()?)),
+            None => Ok(LedgerAddress::Shelley(value.try_to_pla()?)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Ledger address with network information, used for Shelley addresses only
+pub struct LedgerAddressWithExtraInfo<'a> {
+    pub address: &'a LedgerAddress,
+    pub network: &'a Network,
+}
+
+impl TryFromPLA<LedgerAddressWithExtraInfo<'_>> for csl::Address {
+    fn try_from_pla(val: &LedgerAddressWithExtraInfo<'_>) -> Result<Self, TryFromPLAError> {
+        match val.address {
+            LedgerAddress::Shelley(address) => address.with_extra_info(val.network).try_to_csl(),
+            LedgerAddress::Byron(address) => {
+                Ok(csl::ByronAddress::try_from_pla(address)?.to_address())
+            }
+        }
+    }
+}
+
+/// Serializing into a bech32 or base58 address format.
+impl std::fmt::Display for LedgerAddressWithExtraInfo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.address {
+            LedgerAddress::Shelley(address) => {
+                write!(f, "{}", address.with_extra_info(self.network))
+            }
+            LedgerAddress::Byron(address) => write!(f, "{}", address),
+        }
+    }
+}
+
 //////////////////
 // ChainPointer //
 //////////////////
@@ -394,6 +853,16 @@ impl TryFromPLA<ChainPointer> for csl::Pointer {
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct Slot(pub BigInt);
 
+impl Discrete for Slot {
+    fn succ(&self) -> Self {
+        Slot(&self.0 + 1)
+    }
+
+    fn pred(&self) -> Self {
+        Slot(&self.0 - 1)
+    }
+}
+
 impl FromCSL<csl::BigNum> for Slot {
     fn from_csl(value: &csl::BigNum) -> Self {
         Slot(BigInt::from_csl(value))
@@ -452,3 +921,227 @@ impl TryFromPLA<TransactionIndex> for csl::BigNum {
         val.0.try_to_csl()
     }
 }
+
+//////////////////
+// AddressError //
+//////////////////
+
+/// Length of the payment and stake credential hashes in addresses
+const CREDENTIAL_LENGTH: usize = 28;
+
+/// Header address type of Byron addresses, which is the start of their CBOR encoding
+const BYRON_ADDRESS_TYPE: u8 = 0b1000;
+
+/// Error while encoding or decoding an address in the binary or bech32 format of CIP-19
+#[derive(thiserror::Error, Debug)]
+pub enum AddressError {
+    #[error("Address is empty")]
+    Empty,
+
+    #[error("Address type {0:#06b} is not supported")]
+    UnsupportedAddressType(u8),
+
+    #[error("Expected a {expected}, but got address type {got:#06b}")]
+    UnexpectedAddressType { expected: String, got: u8 },
+
+    #[error(
+        "Address of type {address_type:#06b} must be {expected} bytes long, but got {got} bytes"
+    )]
+    InvalidLength {
+        address_type: u8,
+        expected: usize,
+        got: usize,
+    },
+
+    #[error("Variable-length integer of the chain pointer is truncated")]
+    TruncatedVariableLengthInteger,
+
+    #[error("Chain pointer cannot hold the negative integer {0}")]
+    NegativeChainPointer(BigInt),
+
+    #[error("Reward address cannot hold a chain pointer")]
+    PointerRewardAddress,
+
+    #[error("Network tag must fit into 4 bits, but got {0}")]
+    InvalidNetworkTag(u8),
+
+    #[error("Credential hash must be {CREDENTIAL_LENGTH} bytes long, but got {0} bytes")]
+    InvalidCredentialLength(usize),
+
+    #[error("Couldn't decode bech32 address: {0}")]
+    Bech32(#[from] bech32::Error),
+
+    #[error("Expected bech32 prefix '{expected}', but got '{got}'")]
+    InvalidBech32Prefix { expected: String, got: String },
+
+    #[error(transparent)]
+    Byron(#[from] ByronAddressError),
+
+    #[error("Byron address {0} cannot be used where a Plutus address is required")]
+    ByronAddress(ByronAddress),
+
+    #[error("Expected an address of network {expected}, but got network tag {got}")]
+    NetworkMismatch { expected: u8, got: u8 },
+
+    #[error("Expected a Byron address with protocol magic {expected}, but got {got}")]
+    ProtocolMagicMismatch { expected: u32, got: u32 },
+}
+
+/// Split the header byte into the address type and network tag
+fn decode_header(bytes: &[u8]) -> Result<(u8, u8, &[u8]), AddressError> {
+    let (header, payload) = bytes.split_first().ok_or(AddressError::Empty)?;
+
+    Ok((header >> 4, header & 0b1111, payload))
+}
+
+fn encode_header(address_type: u8, network_tag: u8) -> Result<u8, AddressError> {
+    if network_tag > 0b1111 {
+        return Err(AddressError::InvalidNetworkTag(network_tag));
+    }
+
+    Ok(address_type << 4 | network_tag)
+}
+
+fn check_length(address_type: u8, payload: &[u8], expected: usize) -> Result<(), AddressError> {
+    if payload.len() != expected {
+        return Err(AddressError::InvalidLength {
+            address_type,
+            expected: expected + 1,
+            got: payload.len() + 1,
+        });
+    }
+
+    Ok(())
+}
+
+fn is_script(credential: &Credential) -> bool {
+    matches!(credential, Credential::Script(_))
+}
+
+fn decode_credential(is_script: bool, hash: &[u8]) -> Credential {
+    let hash = LedgerBytes(hash.to_vec());
+
+    if is_script {
+        Credential::Script(ValidatorHash(ScriptHash(hash)))
+    } else {
+        Credential::PubKey(Ed25519PubKeyHash(hash))
+    }
+}
+
+fn encode_credential(credential: &Credential) -> Result<&[u8], AddressError> {
+    let hash = match credential {
+        Credential::PubKey(Ed25519PubKeyHash(LedgerBytes(hash))) => hash,
+        Credential::Script(ValidatorHash(ScriptHash(LedgerBytes(hash)))) => hash,
+    };
+
+    if hash.len() != CREDENTIAL_LENGTH {
+        return Err(AddressError::InvalidCredentialLength(hash.len()));
+    }
+
+    Ok(hash)
+}
+
+/// Decode the chain pointer of a pointer address: three variable-length natural numbers,
+/// encoded big-endian in 7 bit groups, with the highest bit of each byte set except for the last
+/// one
+fn decode_chain_pointer(
+    address_type: u8,
+    address_length: usize,
+    bytes: &[u8],
+) -> Result<ChainPointer, AddressError> {
+    let (slot_number, bytes) = decode_variable_nat(bytes)?;
+    let (transaction_index, bytes) = decode_variable_nat(bytes)?;
+    let (certificate_index, bytes) = decode_variable_nat(bytes)?;
+
+    if !bytes.is_empty() {
+        return Err(AddressError::InvalidLength {
+            address_type,
+            expected: address_length - bytes.len(),
+            got: address_length,
+        });
+    }
+
+    Ok(ChainPointer {
+        slot_number: Slot(slot_number),
+        transaction_index: TransactionIndex(transaction_index),
+        certificate_index: CertificateIndex(certificate_index),
+    })
+}
+
+fn encode_chain_pointer(pointer: &ChainPointer, bytes: &mut Vec<u8>) -> Result<(), AddressError> {
+    encode_variable_nat(&pointer.slot_number.0, bytes)?;
+    encode_variable_nat(&pointer.transaction_index.0, bytes)?;
+    encode_variable_nat(&pointer.certificate_index.0, bytes)
+}
+
+fn decode_variable_nat(bytes: &[u8]) -> Result<(BigInt, &[u8]), AddressError> {
+    let mut nat = BigUint::from(0u8);
+
+    for (idx, byte) in bytes.iter().enumerate() {
+        nat = (nat << 7) | BigUint::from(byte & 0x7f);
+
+        if byte & 0x80 == 0 {
+            return Ok((BigInt::from(nat), &bytes[idx + 1..]));
+        }
+    }
+
+    Err(AddressError::TruncatedVariableLengthInteger)
+}
+
+fn encode_variable_nat(nat: &BigInt, bytes: &mut Vec<u8>) -> Result<(), AddressError> {
+    let groups = nat
+        .to_biguint()
+        .ok_or_else(|| AddressError::NegativeChainPointer(nat.clone()))?
+        .to_radix_be(128);
+    let last = groups.len() - 1;
+
+    bytes.extend(groups.into_iter().enumerate().map(|(idx, group)| {
+        if idx == last {
+            group
+        } else {
+            group | 0x80
+        }
+    }));
+
+    Ok(())
+}
+
+fn encode_reward_address(
+    credential: &Credential,
+    network_tag: u8,
+) -> Result<Vec<u8>, AddressError> {
+    let address_type = 0b1110 | is_script(credential) as u8;
+
+    Ok([
+        &[encode_header(address_type, network_tag)?],
+        encode_credential(credential)?,
+    ]
+    .concat())
+}
+
+/// Bech32 prefix of an address on the network: `prefix` for mainnet and `prefix_test` for
+/// testnets
+fn network_prefix(prefix: &str, network_tag: u8) -> String {
+    if network_tag == 1 {
+        prefix.to_string()
+    } else {
+        format!("{}_test", prefix)
+    }
+}
+
+fn decode_bech32(s: &str) -> Result<(String, Vec<u8>), AddressError> {
+    let (prefix, data) = bech32::decode(s)?;
+
+    Ok((prefix, Vec::<u8>::from_base32(&data)?))
+}
+
+fn check_prefix(prefix: &str, expected: String) -> Result<(), AddressError> {
+    if prefix != expected {
+        return Err(AddressError::InvalidBech32Prefix {
+            expected,
+            got: prefix.to_string(),
+        });
+    }
+
+    Ok(())
+}
@@ -1,18 +1,26 @@
 //! Types for cryptographic primitives, and other lower level building blocks
+use std::{fmt, str::FromStr};
+
 use cardano_serialization_lib as csl;
 use data_encoding::HEXLOWER;
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::{Error, Json};
-use nom::{combinator::map_res, error::VerboseError, IResult};
+use nom::{
+    combinator::{all_consuming, map_res},
+    error::VerboseError,
+    Finish, IResult,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate as plutus_ledger_api;
 use crate::{
+    aux::{decode_bech32, encode_bech32, guard_bytes},
     csl::{
         csl_to_pla::FromCSL,
         pla_to_csl::{TryFromPLA, TryFromPLAError},
     },
+    error::ConversionError,
     plutus_data::IsPlutusData,
 };
 
@@ -29,6 +37,50 @@ use crate::{
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct Ed25519PubKeyHash(pub LedgerBytes);
 
+impl Ed25519PubKeyHash {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(Ed25519PubKeyHash(LedgerBytes(guard_bytes(
+            "Ed25519PubKeyHash",
+            bytes,
+            28,
+        )?)))
+    }
+
+    /// Encode in bech32 with one of the key hash prefixes of
+    /// [CIP-5](https://cips.cardano.org/cip/CIP-0005): `addr_vkh` (payment keys), `stake_vkh`
+    /// (stake keys) or `pool` (stake pool ids)
+    pub fn to_bech32(&self, prefix: &str) -> Result<String, ConversionError> {
+        encode_bech32(prefix, &self.0 .0).map_err(|err| {
+            ConversionError::ParseError(anyhow::anyhow!(
+                "Invalid bech32 prefix '{}': {}",
+                prefix,
+                err
+            ))
+        })
+    }
+
+    /// Decode from bech32, expecting one of the key hash prefixes of CIP-5 (see `to_bech32`)
+    pub fn from_bech32(prefix: &str, s: &str) -> Result<Self, ConversionError> {
+        Self::from_bytes(decode_bech32("Ed25519PubKeyHash", prefix, s)?)
+    }
+}
+
+/// Serialize into bech32 with the CIP-5 `addr_vkh` prefix
+/// (e.g. addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg)
+impl fmt::Display for Ed25519PubKeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_bech32("addr_vkh").map_err(|_| fmt::Error)?)
+    }
+}
+
+impl FromStr for Ed25519PubKeyHash {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bech32("addr_vkh", s)
+    }
+}
+
 impl FromCSL<csl::Ed25519KeyHash> for Ed25519PubKeyHash {
     fn from_csl(value: &csl::Ed25519KeyHash) -> Self {
         Ed25519PubKeyHash(LedgerBytes(value.to_bytes()))
@@ -61,6 +113,21 @@ impl FromCSL<csl::RequiredSigners> for Vec<Ed25519PubKeyHash> {
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct PaymentPubKeyHash(pub Ed25519PubKeyHash);
 
+/// Serialize into bech32 with the CIP-5 `addr_vkh` prefix
+impl fmt::Display for PaymentPubKeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for PaymentPubKeyHash {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ed25519PubKeyHash::from_bech32("addr_vkh", s).map(PaymentPubKeyHash)
+    }
+}
+
 /////////////////////
 // StakePubKeyHash //
 /////////////////////
@@ -72,6 +139,161 @@ pub struct PaymentPubKeyHash(pub Ed25519PubKeyHash);
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct StakePubKeyHash(pub Ed25519PubKeyHash);
 
+/// Serialize into bech32 with the CIP-5 `stake_vkh` prefix
+impl fmt::Display for StakePubKeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0.to_bech32("stake_vkh").map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for StakePubKeyHash {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ed25519PubKeyHash::from_bech32("stake_vkh", s).map(StakePubKeyHash)
+    }
+}
+
+////////////
+// PoolId //
+////////////
+
+/// Stake pool id, the blake2b-224 hash of the cold verification key of a stake pool
+///
+/// Plutus represents pool ids as `PubKeyHash`, so the Plutus Data encoding is the same as
+/// `Ed25519PubKeyHash`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, IsPlutusData)]
+#[is_plutus_data_derive_strategy = "Newtype"]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "lbf", derive(Json))]
+pub struct PoolId(pub Ed25519PubKeyHash);
+
+impl PoolId {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(PoolId(Ed25519PubKeyHash(LedgerBytes(guard_bytes(
+            "PoolId", bytes, 28,
+        )?))))
+    }
+
+    /// Encode in bech32 with the `pool` prefix of [CIP-5](https://cips.cardano.org/cip/CIP-0005)
+    pub fn to_bech32(&self) -> Result<String, ConversionError> {
+        self.0.to_bech32("pool")
+    }
+
+    pub fn from_bech32(s: &str) -> Result<Self, ConversionError> {
+        Self::from_bytes(decode_bech32("PoolId", "pool", s)?)
+    }
+
+    pub fn to_hex(&self) -> String {
+        HEXLOWER.encode(&self.0 .0 .0)
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self, ConversionError> {
+        Self::from_bytes(parse_hex("PoolId", s)?)
+    }
+}
+
+/// Serialize into bech32 with the CIP-5 `pool` prefix
+/// (e.g. pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy)
+///
+/// Pool ids that cannot be encoded are displayed as `INVALID POOL ID`, followed by the reason.
+impl fmt::Display for PoolId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_bech32() {
+            Ok(pool_id) => write!(f, "{}", pool_id),
+            Err(err) => write!(f, "INVALID POOL ID {:?}: {}", self, err),
+        }
+    }
+}
+
+/// Parse a pool id in bech32 (`pool1...`) or in hexadecimal
+impl FromStr for PoolId {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("pool1") {
+            Self::from_bech32(s)
+        } else {
+            Self::from_hex(s)
+        }
+    }
+}
+
+impl From<Ed25519PubKeyHash> for PoolId {
+    fn from(value: Ed25519PubKeyHash) -> Self {
+        PoolId(value)
+    }
+}
+
+impl FromCSL<csl::Ed25519KeyHash> for PoolId {
+    fn from_csl(value: &csl::Ed25519KeyHash) -> Self {
+        PoolId(Ed25519PubKeyHash::from_csl(value))
+    }
+}
+
+impl TryFromPLA<PoolId> for csl::Ed25519KeyHash {
+    fn try_from_pla(val: &PoolId) -> Result<Self, TryFromPLAError> {
+        csl::Ed25519KeyHash::try_from_pla(&val.0)
+    }
+}
+
+////////////////
+// VrfKeyHash //
+////////////////
+
+/// Blake2b-256 hash of the VRF verification key of a stake pool
+///
+/// Plutus represents VRF key hashes as `PubKeyHash`, so the Plutus Data encoding is the same as
+/// `Ed25519PubKeyHash`, although the hash is 32 bytes long.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, IsPlutusData)]
+#[is_plutus_data_derive_strategy = "Newtype"]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "lbf", derive(Json))]
+pub struct VrfKeyHash(pub LedgerBytes);
+
+impl VrfKeyHash {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(VrfKeyHash(LedgerBytes(guard_bytes(
+            "VrfKeyHash",
+            bytes,
+            32,
+        )?)))
+    }
+}
+
+/// Serialize into hexadecimal
+impl fmt::Display for VrfKeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parse from hexadecimal
+impl FromStr for VrfKeyHash {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(parse_hex("VrfKeyHash", s)?)
+    }
+}
+
+impl FromCSL<csl::VRFKeyHash> for VrfKeyHash {
+    fn from_csl(value: &csl::VRFKeyHash) -> Self {
+        VrfKeyHash(LedgerBytes(value.to_bytes()))
+    }
+}
+
+impl TryFromPLA<VrfKeyHash> for csl::VRFKeyHash {
+    fn try_from_pla(val: &VrfKeyHash) -> Result<Self, TryFromPLAError> {
+        csl::VRFKeyHash::from_bytes(val.0 .0.to_owned())
+            .map_err(TryFromPLAError::CSLDeserializeError)
+    }
+}
+
 /////////////////
 // LedgerBytes //
 /////////////////
@@ -82,14 +304,14 @@ pub struct StakePubKeyHash(pub Ed25519PubKeyHash);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LedgerBytes(pub Vec<u8>);
 
-impl std::fmt::Debug for LedgerBytes {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl fmt::Debug for LedgerBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", HEXLOWER.encode(&self.0))
     }
 }
 
-impl std::fmt::Display for LedgerBytes {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl fmt::Display for LedgerBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", HEXLOWER.encode(&self.0))
     }
 }
@@ -105,6 +327,21 @@ pub(crate) fn ledger_bytes(input: &str) -> IResult<&str, LedgerBytes, VerboseErr
     })(input)
 }
 
+/// Parse a hexadecimal string, without length restrictions
+fn parse_hex(ctx: &str, s: &str) -> Result<Vec<u8>, ConversionError> {
+    all_consuming(ledger_bytes)(s)
+        .finish()
+        .map(|(_, LedgerBytes(bytes))| bytes)
+        .map_err(|err| {
+            ConversionError::ParseError(anyhow::anyhow!(
+                "Error while parsing {} '{}': {}",
+                ctx,
+                s,
+                err
+            ))
+        })
+}
+
 #[cfg(feature = "lbf")]
 impl Json for LedgerBytes {
     fn to_json(&self) -> serde_json::Value {
@@ -1,26 +1,69 @@
 //! Types related to PlutusInterval
 
+use crate::error::ConversionError;
 use crate::feature_traits::FeatureTraits;
 use crate::plutus_data::{
     parse_constr, parse_constr_with_tag, parse_fixed_len_constr_fields, IsPlutusData, PlutusData,
     PlutusDataError,
 };
+use anyhow::anyhow;
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::Json;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, space0},
+    combinator::{all_consuming, map, map_res},
+    error::{context, VerboseError},
+    sequence::{delimited, separated_pair, tuple},
+    Finish, IResult,
+};
 use num_bigint::BigInt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::cmp;
+use std::str::FromStr;
+use std::{cmp, fmt};
+
+//////////////
+// Discrete //
+//////////////
+
+/// Types where every value has a successor and a predecessor, so open interval bounds can be
+/// turned into closed ones
+pub trait Discrete {
+    fn succ(&self) -> Self;
+    fn pred(&self) -> Self;
+}
+
+impl Discrete for BigInt {
+    fn succ(&self) -> Self {
+        self + 1
+    }
+
+    fn pred(&self) -> Self {
+        self - 1
+    }
+}
 
 //////////////
 // Interval //
 //////////////
 
 /// An abstraction over `PlutusInterval`, allowing valid values only
+///
+/// Every `PlutusInterval` can be represented without changing its members, including ones with
+/// open finite bounds or a start greater than their end.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Interval<T> {
+    /// `[start, end]`
     Finite(T, T),
+    /// `(start, end]`
+    FiniteStartAfter(T, T),
+    /// `[start, end)`
+    FiniteEndBefore(T, T),
+    /// `(start, end)`
+    FiniteOpen(T, T),
     StartAt(T),
     StartAfter(T),
     EndAt(T),
@@ -45,6 +88,36 @@ where
                     closed: true,
                 },
             },
+            Interval::FiniteStartAfter(start, end) => PlutusInterval {
+                from: LowerBound {
+                    bound: Extended::Finite(start),
+                    closed: false,
+                },
+                to: UpperBound {
+                    bound: Extended::Finite(end),
+                    closed: true,
+                },
+            },
+            Interval::FiniteEndBefore(start, end) => PlutusInterval {
+                from: LowerBound {
+                    bound: Extended::Finite(start),
+                    closed: true,
+                },
+                to: UpperBound {
+                    bound: Extended::Finite(end),
+                    closed: false,
+                },
+            },
+            Interval::FiniteOpen(start, end) => PlutusInterval {
+                from: LowerBound {
+                    bound: Extended::Finite(start),
+                    closed: false,
+                },
+                to: UpperBound {
+                    bound: Extended::Finite(end),
+                    closed: false,
+                },
+            },
             Interval::StartAt(start) => PlutusInterval {
                 from: LowerBound {
                     bound: Extended::Finite(start),
@@ -109,123 +182,126 @@ where
     }
 }
 
-#[derive(thiserror::Error, Debug)]
-pub enum TryFromPlutusIntervalError {
-    #[error("Interval is invalid.")]
-    InvalidInterval,
-    #[error("Interval with open bound could not be converted.")]
-    UnexpectedOpenBound,
+impl<T> Interval<T>
+where
+    T: FeatureTraits + Ord + Clone,
+{
+    /// Check whether a value is in the interval
+    pub fn member(&self, value: &T) -> bool {
+        PlutusInterval::from(self.clone()).member(value)
+    }
+
+    /// Check whether the interval contains another interval
+    pub fn contains(&self, other: &Self) -> bool {
+        PlutusInterval::from(self.clone()).contains(&other.clone().into())
+    }
+
+    /// Largest interval contained by both intervals
+    ///
+    /// The result is a `PlutusInterval`, as it might have open bounds on both sides
+    pub fn intersection(&self, other: &Self) -> PlutusInterval<T> {
+        PlutusInterval::from(self.clone()).intersection(&other.clone().into())
+    }
+
+    /// Smallest interval containing both intervals
+    ///
+    /// The result is a `PlutusInterval`, as it might have open bounds on both sides
+    pub fn hull(&self, other: &Self) -> PlutusInterval<T> {
+        PlutusInterval::from(self.clone()).hull(&other.clone().into())
+    }
+
+    /// Check whether a value is earlier than the start of the interval
+    pub fn before(&self, value: &T) -> bool {
+        PlutusInterval::from(self.clone()).before(value)
+    }
+
+    /// Check whether a value is later than the end of the interval
+    pub fn after(&self, value: &T) -> bool {
+        PlutusInterval::from(self.clone()).after(value)
+    }
 }
 
-impl<T> TryFrom<PlutusInterval<T>> for Interval<T>
+impl<T> Interval<T>
 where
-    T: FeatureTraits + PartialOrd,
+    T: FeatureTraits + Discrete + Ord + Clone,
 {
-    type Error = TryFromPlutusIntervalError;
-
-    fn try_from(interval: PlutusInterval<T>) -> Result<Self, Self::Error> {
-        Ok(match interval {
-            PlutusInterval {
-                from:
-                    LowerBound {
-                        bound: Extended::Finite(start),
-                        closed: lc,
-                    },
-                to:
-                    UpperBound {
-                        bound: Extended::Finite(end),
-                        closed: uc,
-                    },
-            } => {
-                if lc && uc {
-                    if start > end {
-                        Err(TryFromPlutusIntervalError::InvalidInterval)?
-                    } else {
-                        Interval::Finite(start, end)
-                    }
-                } else {
-                    Err(TryFromPlutusIntervalError::UnexpectedOpenBound)?
-                }
+    /// Check whether two intervals have any values in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        PlutusInterval::from(self.clone()).overlaps(&other.clone().into())
+    }
+
+    /// Check whether the interval is empty
+    pub fn is_empty(&self) -> bool {
+        PlutusInterval::from(self.clone()).is_empty()
+    }
+
+    /// Equivalent interval with closed bounds, or `Never` if it is empty
+    pub fn normalize(&self) -> Self {
+        let closed = |start: T, end: T| {
+            if start <= end {
+                Interval::Finite(start, end)
+            } else {
+                Interval::Never
             }
-            PlutusInterval {
-                from:
-                    LowerBound {
-                        bound: Extended::Finite(start),
-                        closed: lc,
-                    },
-                to:
-                    UpperBound {
-                        bound: Extended::PosInf,
-                        closed: uc,
-                    },
-            } => {
-                if lc && uc {
+        };
+
+        match self {
+            Interval::Finite(start, end) => closed(start.clone(), end.clone()),
+            Interval::FiniteStartAfter(start, end) => closed(start.succ(), end.clone()),
+            Interval::FiniteEndBefore(start, end) => closed(start.clone(), end.pred()),
+            Interval::FiniteOpen(start, end) => closed(start.succ(), end.pred()),
+            Interval::StartAfter(start) => Interval::StartAt(start.succ()),
+            Interval::EndBefore(end) => Interval::EndAt(end.pred()),
+            interval => interval.clone(),
+        }
+    }
+}
+
+/// Conversion of any `PlutusInterval`, keeping its members
+///
+/// As in Plutus, the `closed` flag of an infinite bound doesn't change the members of an
+/// interval, so it is dropped. A lower bound of `+∞` or an upper bound of `-∞` gives `Never`.
+impl<T> From<PlutusInterval<T>> for Interval<T>
+where
+    T: FeatureTraits,
+{
+    fn from(interval: PlutusInterval<T>) -> Self {
+        let PlutusInterval {
+            from:
+                LowerBound {
+                    bound: start,
+                    closed: lc,
+                },
+            to: UpperBound {
+                bound: end,
+                closed: uc,
+            },
+        } = interval;
+
+        match (start, end) {
+            (Extended::Finite(start), Extended::Finite(end)) => match (lc, uc) {
+                (true, true) => Interval::Finite(start, end),
+                (false, true) => Interval::FiniteStartAfter(start, end),
+                (true, false) => Interval::FiniteEndBefore(start, end),
+                (false, false) => Interval::FiniteOpen(start, end),
+            },
+            (Extended::Finite(start), Extended::PosInf) => {
+                if lc {
                     Interval::StartAt(start)
-                } else if !lc && uc {
-                    Interval::StartAfter(start)
                 } else {
-                    Err(TryFromPlutusIntervalError::UnexpectedOpenBound)?
+                    Interval::StartAfter(start)
                 }
             }
-            PlutusInterval {
-                from:
-                    LowerBound {
-                        bound: Extended::NegInf,
-                        closed: lc,
-                    },
-                to:
-                    UpperBound {
-                        bound: Extended::Finite(end),
-                        closed: uc,
-                    },
-            } => {
-                if uc && lc {
+            (Extended::NegInf, Extended::Finite(end)) => {
+                if uc {
                     Interval::EndAt(end)
-                } else if !uc && lc {
-                    Interval::EndBefore(end)
-                } else {
-                    Err(TryFromPlutusIntervalError::UnexpectedOpenBound)?
-                }
-            }
-            PlutusInterval {
-                from:
-                    LowerBound {
-                        bound: Extended::NegInf,
-                        closed: lc,
-                    },
-                to:
-                    UpperBound {
-                        bound: Extended::PosInf,
-                        closed: uc,
-                    },
-            } => {
-                if lc && uc {
-                    Interval::Always
-                } else {
-                    Err(TryFromPlutusIntervalError::UnexpectedOpenBound)?
-                }
-            }
-            PlutusInterval {
-                from:
-                    LowerBound {
-                        bound: Extended::PosInf,
-                        closed: lc,
-                    },
-                to:
-                    UpperBound {
-                        bound: Extended::NegInf,
-                        closed: uc,
-                    },
-            } => {
-                if lc && uc {
-                    Interval::Never
                 } else {
-                    Err(TryFromPlutusIntervalError::UnexpectedOpenBound)?
+                    Interval::EndBefore(end)
                 }
             }
-
-            _ => Err(TryFromPlutusIntervalError::InvalidInterval)?,
-        })
+            (Extended::NegInf, Extended::PosInf) => Interval::Always,
+            (Extended::PosInf, _) | (_, Extended::NegInf) => Interval::Never,
+        }
     }
 }
 
@@ -258,6 +334,88 @@ where
     pub to: UpperBound<T>,
 }
 
+/// Serialize into mathematical interval notation (e.g. `[1, 5)` or `(-∞, 5]`)
+///
+/// Infinite bounds keep their `closed` flag, so intervals built by `from`, `to` or `always` are
+/// displayed with closed infinities (e.g. `[1, +∞]`). The alternate flag is passed on to the
+/// bounds (e.g.: format!("{:#}", range) displays POSIX times as ISO-8601 timestamps with the
+/// `chrono` feature)
+impl<T> fmt::Display for PlutusInterval<T>
+where
+    T: FeatureTraits + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.from.closed {
+            "[".fmt(f)?;
+        } else {
+            "(".fmt(f)?;
+        }
+
+        self.from.bound.fmt(f)?;
+        ", ".fmt(f)?;
+        self.to.bound.fmt(f)?;
+
+        if self.to.closed {
+            "]".fmt(f)
+        } else {
+            ")".fmt(f)
+        }
+    }
+}
+
+impl<T> FromStr for PlutusInterval<T>
+where
+    T: FeatureTraits + FromStr,
+{
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all_consuming(plutus_interval)(s)
+            .finish()
+            .map_err(|err| {
+                ConversionError::ParseError(anyhow!(
+                    "Error while parsing PlutusInterval '{}': {}",
+                    s,
+                    err
+                ))
+            })
+            .map(|(_, interval)| interval)
+    }
+}
+
+/// Nom parser for PlutusInterval
+/// Expects two bounds divided by a `,`, surrounded by `[` or `(` and `]` or `)` for closed and
+/// open bounds respectively, for both finite and infinite bounds.
+/// E.g.: [1700000000000, +∞]
+pub(crate) fn plutus_interval<T>(
+    input: &str,
+) -> IResult<&str, PlutusInterval<T>, VerboseError<&str>>
+where
+    T: FeatureTraits + FromStr,
+{
+    map(
+        tuple((
+            alt((char('['), char('('))),
+            separated_pair(
+                delimited(space0, extended, space0),
+                char(','),
+                delimited(space0, extended, space0),
+            ),
+            alt((char(']'), char(')'))),
+        )),
+        move |(open, (from, to), close)| PlutusInterval {
+            from: LowerBound {
+                closed: open == '[',
+                bound: from,
+            },
+            to: UpperBound {
+                closed: close == ']',
+                bound: to,
+            },
+        },
+    )(input)
+}
+
 impl<T> IsPlutusData for PlutusInterval<T>
 where
     T: FeatureTraits + IsPlutusData,
@@ -279,6 +437,155 @@ where
     }
 }
 
+impl<T> PlutusInterval<T>
+where
+    T: FeatureTraits + Ord + Clone,
+{
+    /// Check whether a value is in the interval
+    pub fn member(&self, value: &T) -> bool {
+        self.contains(&singleton(value.clone()))
+    }
+
+    /// Check whether the interval contains another interval, i.e. every value of the other
+    /// interval is also in this one
+    pub fn contains(&self, other: &Self) -> bool {
+        self.from.cmp_lower(&other.from) != cmp::Ordering::Greater
+            && other.to.cmp_upper(&self.to) != cmp::Ordering::Greater
+    }
+
+    /// Largest interval contained by both intervals
+    pub fn intersection(&self, other: &Self) -> Self {
+        PlutusInterval {
+            from: cmp::max_by(self.from.clone(), other.from.clone(), LowerBound::cmp_lower),
+            to: cmp::min_by(self.to.clone(), other.to.clone(), UpperBound::cmp_upper),
+        }
+    }
+
+    /// Smallest interval containing both intervals
+    pub fn hull(&self, other: &Self) -> Self {
+        PlutusInterval {
+            from: cmp::min_by(self.from.clone(), other.from.clone(), LowerBound::cmp_lower),
+            to: cmp::max_by(self.to.clone(), other.to.clone(), UpperBound::cmp_upper),
+        }
+    }
+
+    /// Check whether a value is earlier than the start of the interval
+    pub fn before(&self, value: &T) -> bool {
+        LowerBound::finite(value.clone()).cmp_lower(&self.from) == cmp::Ordering::Less
+    }
+
+    /// Check whether a value is later than the end of the interval
+    pub fn after(&self, value: &T) -> bool {
+        UpperBound::finite(value.clone()).cmp_upper(&self.to) == cmp::Ordering::Greater
+    }
+}
+
+impl<T> PlutusInterval<T>
+where
+    T: FeatureTraits + Discrete + Ord + Clone,
+{
+    /// Check whether two intervals have any values in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Check whether the interval is empty
+    ///
+    /// An open interval between two consecutive values (e.g. `(1, 2)` of integers) is empty.
+    pub fn is_empty(&self) -> bool {
+        match self.from.bound.cmp(&self.to.bound) {
+            cmp::Ordering::Less => match (&self.from.bound, &self.to.bound) {
+                (Extended::Finite(start), Extended::Finite(end))
+                    if !self.from.closed && !self.to.closed =>
+                {
+                    &start.succ() == end
+                }
+                _ => false,
+            },
+            cmp::Ordering::Greater => true,
+            cmp::Ordering::Equal => !(self.from.closed && self.to.closed),
+        }
+    }
+
+    /// Equivalent interval with closed bounds, or `never` if it is empty
+    ///
+    /// Intervals starting at positive infinity or ending at negative infinity are also considered
+    /// empty, as they don't contain any finite values.
+    pub fn normalize(&self) -> Self {
+        let from = match &self.from.bound {
+            Extended::Finite(start) if !self.from.closed => LowerBound::finite(start.succ()),
+            bound => LowerBound {
+                bound: bound.clone(),
+                closed: true,
+            },
+        };
+
+        let to = match &self.to.bound {
+            Extended::Finite(end) if !self.to.closed => UpperBound::finite(end.pred()),
+            bound => UpperBound {
+                bound: bound.clone(),
+                closed: true,
+            },
+        };
+
+        let interval = PlutusInterval { from, to };
+
+        if interval.is_empty()
+            || interval.from.bound == Extended::PosInf
+            || interval.to.bound == Extended::NegInf
+        {
+            never()
+        } else {
+            interval
+        }
+    }
+}
+
+/// Closed interval from `start` to `end`, both inclusive
+pub fn interval<T: FeatureTraits>(start: T, end: T) -> PlutusInterval<T> {
+    PlutusInterval {
+        from: LowerBound::finite(start),
+        to: UpperBound::finite(end),
+    }
+}
+
+/// Interval containing only the given value
+pub fn singleton<T: FeatureTraits + Clone>(value: T) -> PlutusInterval<T> {
+    interval(value.clone(), value)
+}
+
+/// Interval from `start` (inclusive) until positive infinity
+pub fn from<T: FeatureTraits>(start: T) -> PlutusInterval<T> {
+    PlutusInterval {
+        from: LowerBound::finite(start),
+        to: UpperBound {
+            bound: Extended::PosInf,
+            closed: true,
+        },
+    }
+}
+
+/// Interval from negative infinity until `end` (inclusive)
+pub fn to<T: FeatureTraits>(end: T) -> PlutusInterval<T> {
+    PlutusInterval {
+        from: LowerBound {
+            bound: Extended::NegInf,
+            closed: true,
+        },
+        to: UpperBound::finite(end),
+    }
+}
+
+/// Interval containing all values
+pub fn always<T: FeatureTraits>() -> PlutusInterval<T> {
+    Interval::Always.into()
+}
+
+/// Empty interval
+pub fn never<T: FeatureTraits>() -> PlutusInterval<T> {
+    Interval::Never.into()
+}
+
 ////////////////
 // UpperBound //
 ////////////////
@@ -294,6 +601,29 @@ where
     pub closed: bool,
 }
 
+impl<T> UpperBound<T>
+where
+    T: FeatureTraits,
+{
+    /// Inclusive finite upper bound
+    pub fn finite(value: T) -> Self {
+        UpperBound {
+            bound: Extended::Finite(value),
+            closed: true,
+        }
+    }
+
+    /// Compare upper bounds by the values they include: on equal bounds, the closed one is greater
+    fn cmp_upper(&self, other: &Self) -> cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.bound
+            .cmp(&other.bound)
+            .then(self.closed.cmp(&other.closed))
+    }
+}
+
 impl<T> IsPlutusData for UpperBound<T>
 where
     T: FeatureTraits + IsPlutusData,
@@ -330,6 +660,29 @@ where
     pub closed: bool,
 }
 
+impl<T> LowerBound<T>
+where
+    T: FeatureTraits,
+{
+    /// Inclusive finite lower bound
+    pub fn finite(value: T) -> Self {
+        LowerBound {
+            bound: Extended::Finite(value),
+            closed: true,
+        }
+    }
+
+    /// Compare lower bounds by the values they include: on equal bounds, the closed one is smaller
+    fn cmp_lower(&self, other: &Self) -> cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.bound
+            .cmp(&other.bound)
+            .then(other.closed.cmp(&self.closed))
+    }
+}
+
 impl<T> IsPlutusData for LowerBound<T>
 where
     T: FeatureTraits + IsPlutusData,
@@ -410,6 +763,44 @@ where
     }
 }
 
+/// Serialize infinities as `-∞` and `+∞`, and finite values using their own `Display`
+/// implementation
+impl<T> fmt::Display for Extended<T>
+where
+    T: FeatureTraits + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Extended::NegInf => "-∞".fmt(f),
+            Extended::Finite(value) => value.fmt(f),
+            Extended::PosInf => "+∞".fmt(f),
+        }
+    }
+}
+
+/// Nom parser for Extended
+/// Expects `-∞` or `-inf`, `+∞`, `∞`, `+inf` or `inf`, or a value parsed by its `FromStr`
+/// implementation, ending before a `,`, `]` or `)`
+pub(crate) fn extended<T>(input: &str) -> IResult<&str, Extended<T>, VerboseError<&str>>
+where
+    T: FeatureTraits + FromStr,
+{
+    context(
+        "extended",
+        alt((
+            map(alt((tag("-∞"), tag("-inf"))), |_| Extended::NegInf),
+            map(
+                alt((tag("+∞"), tag("∞"), tag("+inf"), tag("inf"))),
+                |_| Extended::PosInf,
+            ),
+            map_res(
+                take_till1(|c| c == ',' || c == ']' || c == ')'),
+                |value: &str| value.trim_end().parse().map(Extended::Finite),
+            ),
+        )),
+    )(input)
+}
+
 impl<T> IsPlutusData for Extended<T>
 where
     T: FeatureTraits + IsPlutusData,
@@ -450,14 +841,204 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{generators::correct::v1::arb_interval_posix_time, v1::transaction::POSIXTime};
+    use crate::{
+        generators::correct::v1::{arb_interval_posix_time, arb_plutus_interval},
+        v1::transaction::POSIXTime,
+    };
     use proptest::prelude::*;
 
+    /// Small values, so bounds are often shared between intervals and tested values
+    fn arb_small_int() -> impl Strategy<Value = BigInt> {
+        (0..10).prop_map(BigInt::from)
+    }
+
+    fn arb_small_interval() -> impl Strategy<Value = PlutusInterval<BigInt>> {
+        arb_plutus_interval(arb_small_int(), arb_small_int())
+    }
+
+    #[test]
+    fn interval_bounds() {
+        let i = interval(BigInt::from(1), BigInt::from(3));
+
+        assert!(i.member(&BigInt::from(1)));
+        assert!(i.member(&BigInt::from(3)));
+        assert!(!i.member(&BigInt::from(4)));
+        assert!(i.before(&BigInt::from(0)));
+        assert!(!i.before(&BigInt::from(1)));
+        assert!(i.after(&BigInt::from(4)));
+        assert!(!i.after(&BigInt::from(3)));
+
+        let open = PlutusInterval {
+            from: LowerBound {
+                bound: Extended::Finite(BigInt::from(1)),
+                closed: false,
+            },
+            to: UpperBound {
+                bound: Extended::Finite(BigInt::from(3)),
+                closed: false,
+            },
+        };
+
+        assert!(!open.member(&BigInt::from(1)));
+        assert!(open.member(&BigInt::from(2)));
+        assert!(!open.member(&BigInt::from(3)));
+        assert!(open.before(&BigInt::from(1)));
+        assert!(open.after(&BigInt::from(3)));
+        assert!(i.contains(&open));
+        assert!(!open.contains(&i));
+
+        assert_eq!(
+            Interval::from(open.clone()),
+            Interval::FiniteOpen(BigInt::from(1), BigInt::from(3))
+        );
+        assert_eq!(open.normalize(), interval(BigInt::from(2), BigInt::from(2)));
+        assert!(Interval::FiniteOpen(BigInt::from(1), BigInt::from(2))
+            .normalize()
+            .is_empty());
+
+        assert!(singleton(BigInt::from(1)).member(&BigInt::from(1)));
+        assert!(from(BigInt::from(1)).member(&BigInt::from(1000)));
+        assert!(to(BigInt::from(1)).member(&BigInt::from(-1000)));
+        assert!(never::<BigInt>().is_empty());
+        assert!(!always::<BigInt>().is_empty());
+        assert!(!interval(BigInt::from(3), BigInt::from(1)).overlaps(&always()));
+    }
+
+    #[test]
+    fn discrete_emptiness() {
+        let open = |start: i64, end: i64| PlutusInterval {
+            from: LowerBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(start))),
+                closed: false,
+            },
+            to: UpperBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(end))),
+                closed: false,
+            },
+        };
+
+        assert!(open(0, 1).is_empty());
+        assert!(!open(0, 2).is_empty());
+        assert!(
+            Interval::FiniteOpen(POSIXTime(BigInt::from(0)), POSIXTime(BigInt::from(1))).is_empty()
+        );
+
+        let before_one = PlutusInterval {
+            from: LowerBound {
+                bound: Extended::NegInf,
+                closed: true,
+            },
+            to: UpperBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(1))),
+                closed: false,
+            },
+        };
+        let after_zero = PlutusInterval {
+            from: LowerBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(0))),
+                closed: false,
+            },
+            to: UpperBound {
+                bound: Extended::PosInf,
+                closed: true,
+            },
+        };
+
+        assert!(!before_one.overlaps(&after_zero));
+        assert!(!Interval::EndBefore(POSIXTime(BigInt::from(1)))
+            .overlaps(&Interval::StartAfter(POSIXTime(BigInt::from(0)))));
+    }
+
     proptest! {
+        #[test]
+        fn intersection_membership(i in arb_small_interval(), j in arb_small_interval(), x in arb_small_int()) {
+            assert_eq!(i.intersection(&j).member(&x), i.member(&x) && j.member(&x));
+        }
+
+        #[test]
+        fn hull_contains_both(i in arb_small_interval(), j in arb_small_interval()) {
+            let hull = i.hull(&j);
+
+            assert!(hull.contains(&i));
+            assert!(hull.contains(&j));
+            assert!(i.contains(&i.intersection(&j)));
+            assert!(j.contains(&i.intersection(&j)));
+        }
+
+        #[test]
+        fn overlaps_symmetric(i in arb_small_interval(), j in arb_small_interval(), x in arb_small_int()) {
+            assert_eq!(i.overlaps(&j), j.overlaps(&i));
+
+            if i.member(&x) && j.member(&x) {
+                assert!(i.overlaps(&j));
+            }
+        }
+
+        #[test]
+        fn empty_has_no_members(i in arb_small_interval(), x in arb_small_int()) {
+            if i.is_empty() {
+                assert!(!i.member(&x));
+            }
+        }
+
+        #[test]
+        fn empty_normalizes_to_never(i in arb_small_interval()) {
+            if i.is_empty() {
+                assert_eq!(i.normalize(), never());
+            }
+        }
+
+        #[test]
+        fn before_and_after(i in arb_small_interval(), x in arb_small_int(), y in arb_small_int()) {
+            if i.member(&y) {
+                assert!(!i.before(&y) && !i.after(&y));
+
+                if i.before(&x) {
+                    assert!(x < y);
+                }
+
+                if i.after(&x) {
+                    assert!(x > y);
+                }
+            }
+        }
+
+        #[test]
+        fn normalize_keeps_members(i in arb_small_interval(), x in arb_small_int()) {
+            let normalized = i.normalize();
+
+            assert_eq!(normalized.member(&x), i.member(&x));
+        }
+
+        #[test]
+        fn conversion_keeps_members(i in arb_small_interval(), x in arb_small_int()) {
+            let converted = Interval::from(i.clone());
+
+            assert_eq!(converted.member(&x), i.member(&x));
+        }
+
+        #[test]
+        fn normalize_interval(i in arb_interval_posix_time()) {
+            assert_eq!(
+                PlutusInterval::from(i.normalize()),
+                PlutusInterval::from(i).normalize()
+            );
+        }
+
+        #[test]
+        fn interval_operations_agree(i in arb_interval_posix_time(), j in arb_interval_posix_time()) {
+            let (pi, pj) = (PlutusInterval::from(i.clone()), PlutusInterval::from(j.clone()));
+
+            assert_eq!(i.contains(&j), pi.contains(&pj));
+            assert_eq!(i.overlaps(&j), pi.overlaps(&pj));
+            assert_eq!(i.intersection(&j), pi.intersection(&pj));
+            assert_eq!(i.hull(&j), pi.hull(&pj));
+        }
+
         #[test]
         fn interval_to_from_plutus_interval(interval in arb_interval_posix_time()) {
             let plutus_interval: PlutusInterval<POSIXTime> = interval.clone().into();
-            let interval2: Interval<POSIXTime> = plutus_interval.clone().try_into().unwrap();
+            let interval2: Interval<POSIXTime> = plutus_interval.clone().into();
 
             assert_eq!(interval, interval2);
         }
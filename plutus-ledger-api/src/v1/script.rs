@@ -1,6 +1,11 @@
 //! Types related to Plutus scripts
+use std::io::Cursor;
+use std::{fmt, str::FromStr};
 
 use cardano_serialization_lib as csl;
+use cbor_event::{de::Deserializer, se::Serializer};
+use cryptoxide::hashing::blake2b::Blake2b;
+use data_encoding::HEXLOWER_PERMISSIVE;
 
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::Json;
@@ -8,12 +13,15 @@ use lbr_prelude::json::Json;
 use serde::{Deserialize, Serialize};
 
 use crate as plutus_ledger_api;
-use crate::aux::guard_bytes;
+use crate::aux::{decode_bech32, encode_bech32, guard_bytes};
 use crate::csl::csl_to_pla::FromCSL;
 use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
 use crate::error::ConversionError;
+use crate::native_script::NativeScript;
 use crate::plutus_data::IsPlutusData;
+use crate::v1::address::{Address, Credential, StakingCredential};
 use crate::v1::crypto::LedgerBytes;
+use crate::v1::value::CurrencySymbol;
 
 ///////////////////
 // ValidatorHash //
@@ -88,6 +96,25 @@ impl ScriptHash {
     }
 }
 
+/// Serialize into bech32 with the CIP-5 `script` prefix
+impl fmt::Display for ScriptHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_bech32("script", &self.0 .0).map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for ScriptHash {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(decode_bech32("ScriptHash", "script", s)?)
+    }
+}
+
 impl FromCSL<csl::ScriptHash> for ScriptHash {
     fn from_csl(value: &csl::ScriptHash) -> Self {
         ScriptHash(LedgerBytes(value.to_bytes()))
@@ -100,3 +127,215 @@ impl TryFromPLA<ScriptHash> for csl::ScriptHash {
             .map_err(TryFromPLAError::CSLDeserializeError)
     }
 }
+
+//////////////////
+// PlutusScript //
+//////////////////
+
+/// Version of the Plutus language a script is compiled for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlutusLanguage {
+    V1,
+    V2,
+    V3,
+}
+
+impl PlutusLanguage {
+    /// Tag prepended to the script bytes when hashing them
+    fn hash_tag(&self) -> u8 {
+        match self {
+            PlutusLanguage::V1 => 1,
+            PlutusLanguage::V2 => 2,
+            PlutusLanguage::V3 => 3,
+        }
+    }
+}
+
+impl FromCSL<csl::Language> for PlutusLanguage {
+    fn from_csl(value: &csl::Language) -> Self {
+        match value.kind() {
+            csl::LanguageKind::PlutusV1 => PlutusLanguage::V1,
+            csl::LanguageKind::PlutusV2 => PlutusLanguage::V2,
+            csl::LanguageKind::PlutusV3 => PlutusLanguage::V3,
+        }
+    }
+}
+
+impl TryFromPLA<PlutusLanguage> for csl::Language {
+    fn try_from_pla(val: &PlutusLanguage) -> Result<Self, TryFromPLAError> {
+        Ok(match val {
+            PlutusLanguage::V1 => csl::Language::new_plutus_v1(),
+            PlutusLanguage::V2 => csl::Language::new_plutus_v2(),
+            PlutusLanguage::V3 => csl::Language::new_plutus_v3(),
+        })
+    }
+}
+
+/// A compiled Plutus script
+///
+/// `bytes` holds the flat encoded program wrapped in a single CBOR bytestring, the format used by
+/// the `compiledCode` field of CIP-57 blueprints and by cardano-serialization-lib.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlutusScript {
+    pub language: PlutusLanguage,
+    pub bytes: LedgerBytes,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PlutusScriptError {
+    #[error("Unable to decode the CBOR of a Plutus script: {0}")]
+    Cbor(#[from] cbor_event::Error),
+
+    #[error("String cannot be parsed as a hexadecimal value: {0}")]
+    Hex(#[from] data_encoding::DecodeError),
+
+    #[error("Unexpected bytes after the end of the script")]
+    TrailingBytes,
+}
+
+impl PlutusScript {
+    /// Build a script from its flat encoded program, without any CBOR wrapping
+    pub fn from_flat(language: PlutusLanguage, flat: &[u8]) -> Self {
+        let mut bytes = Serializer::new_vec();
+        bytes
+            .write_bytes(flat)
+            .expect("Writing to a vector cannot fail");
+
+        PlutusScript {
+            language,
+            bytes: LedgerBytes(bytes.finalize()),
+        }
+    }
+
+    /// Decode a script wrapped in one or two CBOR bytestrings
+    ///
+    /// Both the single wrapped `compiledCode` of CIP-57 blueprints and the double wrapped
+    /// `cborHex` of cardano-cli text envelopes are accepted, and normalized to a single wrapping.
+    /// A flat program always starts with its major version, so it can never be mistaken for a
+    /// CBOR bytestring.
+    pub fn from_cbor(language: PlutusLanguage, bytes: &[u8]) -> Result<Self, PlutusScriptError> {
+        let inner = unwrap_cbor_bytes(bytes)?;
+
+        let bytes = if unwrap_cbor_bytes(&inner).is_ok() {
+            inner
+        } else {
+            bytes.to_vec()
+        };
+
+        Ok(PlutusScript {
+            language,
+            bytes: LedgerBytes(bytes),
+        })
+    }
+
+    /// Decode a hex encoded script wrapped in one or two CBOR bytestrings
+    pub fn from_cbor_hex(language: PlutusLanguage, s: &str) -> Result<Self, PlutusScriptError> {
+        Self::from_cbor(language, &HEXLOWER_PERMISSIVE.decode(s.as_bytes())?)
+    }
+
+    /// Blake2b-224 hash of the script bytes, prefixed with the language tag
+    pub fn hash(&self) -> ScriptHash {
+        let mut hash = vec![0; 28];
+        Blake2b::<224>::new()
+            .update(&[self.language.hash_tag()])
+            .update(&self.bytes.0)
+            .finalize_at(&mut hash);
+
+        ScriptHash(LedgerBytes(hash))
+    }
+
+    pub fn validator_hash(&self) -> ValidatorHash {
+        ValidatorHash(self.hash())
+    }
+
+    pub fn minting_policy_hash(&self) -> MintingPolicyHash {
+        MintingPolicyHash(self.hash())
+    }
+
+    /// Currency symbol of the tokens minted by this script, used as a minting policy
+    pub fn currency_symbol(&self) -> CurrencySymbol {
+        CurrencySymbol::NativeToken(self.minting_policy_hash())
+    }
+
+    /// Address of the script, used as a validator
+    ///
+    /// Without a staking credential this is an enterprise address, otherwise a base or pointer
+    /// address.
+    pub fn address(&self, staking_credential: Option<StakingCredential>) -> Address {
+        Address {
+            credential: Credential::Script(self.validator_hash()),
+            staking_credential,
+        }
+    }
+}
+
+/// Decode a CBOR bytestring spanning the whole input
+fn unwrap_cbor_bytes(bytes: &[u8]) -> Result<Vec<u8>, PlutusScriptError> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let inner = raw.bytes()?;
+
+    if raw.inner().position() == bytes.len() as u64 {
+        Ok(inner)
+    } else {
+        Err(PlutusScriptError::TrailingBytes)
+    }
+}
+
+impl FromCSL<csl::PlutusScript> for PlutusScript {
+    fn from_csl(value: &csl::PlutusScript) -> Self {
+        PlutusScript {
+            language: PlutusLanguage::from_csl(&value.language_version()),
+            bytes: LedgerBytes(value.bytes()),
+        }
+    }
+}
+
+impl TryFromPLA<PlutusScript> for csl::PlutusScript {
+    fn try_from_pla(val: &PlutusScript) -> Result<Self, TryFromPLAError> {
+        Ok(csl::PlutusScript::new_with_version(
+            val.bytes.0.to_owned(),
+            &val.language.try_to_csl()?,
+        ))
+    }
+}
+
+////////////
+// Script //
+////////////
+
+/// A native or Plutus script, as attached to outputs as a reference script
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Script {
+    Native(NativeScript),
+    Plutus(PlutusScript),
+}
+
+impl Script {
+    pub fn hash(&self) -> ScriptHash {
+        match self {
+            Script::Native(script) => script.hash(),
+            Script::Plutus(script) => script.hash(),
+        }
+    }
+}
+
+impl FromCSL<csl::ScriptRef> for Script {
+    fn from_csl(value: &csl::ScriptRef) -> Self {
+        match value.native_script() {
+            Some(script) => Script::Native(NativeScript::from_csl(&script)),
+            None => Script::Plutus(PlutusScript::from_csl(&value.plutus_script().unwrap())),
+        }
+    }
+}
+
+impl TryFromPLA<Script> for csl::ScriptRef {
+    fn try_from_pla(val: &Script) -> Result<Self, TryFromPLAError> {
+        Ok(match val {
+            Script::Native(script) => csl::ScriptRef::new_native_script(&script.try_to_csl()?),
+            Script::Plutus(script) => csl::ScriptRef::new_plutus_script(&script.try_to_csl()?),
+        })
+    }
+}
@@ -6,10 +6,12 @@ use cardano_serialization_lib as csl;
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::Json;
 use nom::{
-    character::complete::char,
+    branch::alt,
+    bytes::complete::take_till1,
+    character::complete::{char, multispace0},
     combinator::{all_consuming, map, map_res},
     error::{context, VerboseError},
-    sequence::{preceded, tuple},
+    sequence::{delimited, preceded, tuple},
     Finish, IResult,
 };
 use num_bigint::BigInt;
@@ -18,12 +20,13 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     address::{Address, StakingCredential},
-    crypto::{ledger_bytes, LedgerBytes, PaymentPubKeyHash},
+    crypto::{ledger_bytes, LedgerBytes, PaymentPubKeyHash, PoolId, VrfKeyHash},
     datum::{Datum, DatumHash},
-    interval::PlutusInterval,
-    value::{CurrencySymbol, Value},
+    interval::{Discrete, PlutusInterval},
+    value::{cli_value, CurrencySymbol, Value},
 };
 
+use crate::keys::witness::{verify_witnesses, VKeyWitness, WitnessError};
 use crate::{
     self as plutus_ledger_api,
     aux::{big_int, guard_bytes},
@@ -219,6 +222,44 @@ pub struct TransactionOutput {
     pub datum_hash: Option<DatumHash>,
 }
 
+/// Parse an address and value in the syntax of the cardano-cli `--tx-out` argument
+///
+/// The bech32 address is followed by a `+` and a value in cardano-cli syntax (see
+/// `Value::from_cli_str`). Either the whole string or the value can be surrounded by double quotes.
+/// E.g.: addr_test1...+2000000 lovelace + 5 11223344556677889900112233445566778899001122334455667788.001122
+pub fn parse_cli_tx_out(s: &str) -> Result<(Address, Value), ConversionError> {
+    all_consuming(cli_tx_out)(s)
+        .finish()
+        .map_err(|err| {
+            ConversionError::ParseError(anyhow!(
+                "Error while parsing cardano-cli transaction output '{}': {}",
+                s,
+                err
+            ))
+        })
+        .map(|(_, tx_out)| tx_out)
+}
+
+/// Nom parser for a cardano-cli transaction output, optionally surrounded by double quotes
+/// Expects a bech32 address, followed by a `+` (optionally surrounded by whitespace) and a
+/// cardano-cli value
+pub(crate) fn cli_tx_out(input: &str) -> IResult<&str, (Address, Value), VerboseError<&str>> {
+    let tx_out = || {
+        tuple((
+            context(
+                "address",
+                map_res(
+                    take_till1(|c: char| c == '+' || c == '"' || c.is_whitespace()),
+                    Address::from_str,
+                ),
+            ),
+            preceded(delimited(multispace0, char('+'), multispace0), cli_value),
+        ))
+    };
+
+    alt((delimited(char('"'), tx_out(), char('"')), tx_out()))(input)
+}
+
 ///////////////
 // POSIXTime //
 ///////////////
@@ -230,6 +271,60 @@ pub struct TransactionOutput {
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct POSIXTime(pub BigInt);
 
+/// Serialize into the number of milliseconds
+/// With the `chrono` feature, it serializes into an ISO-8601 timestamp when the alternate flag is
+/// used (e.g.: format!("{:#}", time)), if the time can be represented as a date
+impl fmt::Display for POSIXTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "chrono")]
+        if f.alternate() {
+            if let Ok(datetime) = chrono::DateTime::<chrono::Utc>::try_from(self.clone()) {
+                return write!(
+                    f,
+                    "{}",
+                    datetime.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+                );
+            }
+        }
+
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parse from the number of milliseconds, or with the `chrono` feature, from an ISO-8601
+/// (RFC 3339) timestamp
+impl FromStr for POSIXTime {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "chrono")]
+        if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(s) {
+            return Ok(POSIXTime::from(datetime));
+        }
+
+        all_consuming(big_int)(s)
+            .finish()
+            .map_err(|err| {
+                ConversionError::ParseError(anyhow!(
+                    "Error while parsing POSIXTime '{}': {}",
+                    s,
+                    err
+                ))
+            })
+            .map(|(_, millis)| POSIXTime(millis))
+    }
+}
+
+impl Discrete for POSIXTime {
+    fn succ(&self) -> Self {
+        POSIXTime(&self.0 + 1)
+    }
+
+    fn pred(&self) -> Self {
+        POSIXTime(&self.0 - 1)
+    }
+}
+
 #[cfg(feature = "chrono")]
 #[derive(thiserror::Error, Debug)]
 pub enum POSIXTimeConversionError {
@@ -301,17 +396,17 @@ pub enum DCert {
         /// Delegator
         StakingCredential,
         /// Delegatee
-        PaymentPubKeyHash,
+        PoolId,
     ),
     /// A digest of the PoolParam
     PoolRegister(
         /// Pool id
-        PaymentPubKeyHash,
-        /// Pool VFR
-        PaymentPubKeyHash,
+        PoolId,
+        /// Pool VRF
+        VrfKeyHash,
     ),
     PoolRetire(
-        PaymentPubKeyHash,
+        PoolId,
         /// Epoch
         BigInt,
     ),
@@ -357,6 +452,13 @@ pub struct TransactionInfo {
     pub id: TransactionHash,
 }
 
+impl TransactionInfo {
+    /// Verify that the witnesses sign the transaction id, and that all signatories signed it
+    pub fn verify_witnesses(&self, witnesses: &[VKeyWitness]) -> Result<(), WitnessError> {
+        verify_witnesses(&self.id, &self.signatories, witnesses)
+    }
+}
+
 ///////////////////
 // ScriptContext //
 ///////////////////
@@ -3,36 +3,40 @@
 use std::str::FromStr;
 use std::string::String;
 use std::{
+    cmp::Ordering,
     collections::BTreeMap,
     iter::Sum,
-    ops::{Add, Mul, Neg, Not, Sub},
+    ops::{Add, AddAssign, Mul, Neg, Not, Sub, SubAssign},
 };
 use std::{fmt, ops};
 
 use anyhow::anyhow;
+use bech32::{FromBase32, ToBase32};
 use cardano_serialization_lib as csl;
+use cryptoxide::hashing::blake2b::Blake2b;
 #[cfg(feature = "lbf")]
 use lbr_prelude::json::{Error, Json, JsonType};
 use nom::combinator::{map, opt};
 use nom::{
     branch::alt,
-    character::complete::{char, space0},
-    combinator::{all_consuming, eof, map_res, success},
+    bytes::complete::tag,
+    character::complete::{char, space0, space1},
+    combinator::{all_consuming, eof, map_res, success, verify},
     error::{context, VerboseError},
-    multi::separated_list0,
-    sequence::preceded,
-    sequence::tuple,
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, preceded, tuple},
     Finish, IResult,
 };
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_traits::{Signed, Zero};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "lbf")]
 use serde_json;
 
 use crate as plutus_ledger_api;
-use crate::aux::{big_int, singleton, union_b_tree_maps_with, union_btree_maps_with};
+use crate::aux::{big_int, guard_bytes, union_b_tree_maps_with};
+use crate::cbor::{bytes_size, head_size, integer_size};
 use crate::csl::csl_to_pla::FromCSL;
 use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
 use crate::error::ConversionError;
@@ -169,10 +173,25 @@ pub(crate) fn currency_symbol(input: &str) -> IResult<&str, CurrencySymbol, Verb
 // Value //
 ///////////
 
+#[derive(thiserror::Error, Debug)]
+pub enum ValuePartitionError {
+    #[error("Asset {0} doesn't fit into a single value within the given limits")]
+    AssetTooLarge(AssetClass),
+}
+
 /// A value that can contain multiple asset classes
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-#[cfg_attr(feature = "lbf", derive(Json))]
-pub struct Value(pub BTreeMap<CurrencySymbol, BTreeMap<TokenName, BigInt>>);
+///
+/// `Value` has no `PartialOrd` instance, as its natural order is only partial. Use `geq`, `leq`,
+/// `gt` and `lt` to compare values the same way Plutus does.
+///
+/// Tokens are stored in vectors sorted by currency symbol and token name, so values can be added
+/// together in place by merging them. Use `iter`, `currency_symbols` and `tokens` to inspect a
+/// value, and `From` conversions to build it from (or turn it into) a nested `BTreeMap`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Value(Vec<(CurrencySymbol, Tokens)>);
+
+/// Quantities of the tokens of a currency symbol, sorted by token name
+type Tokens = Vec<(TokenName, BigInt)>;
 
 #[cfg(feature = "serde")]
 mod value_serde {
@@ -183,18 +202,12 @@ mod value_serde {
 
     use super::{CurrencySymbol, TokenName, Value};
 
-    struct Assets(BTreeMap<TokenName, BigInt>);
-
     impl Serialize for Value {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            serializer.collect_seq(
-                self.0
-                    .iter()
-                    .map(|(cur_sym, assets)| (cur_sym, Assets(assets.to_owned()))),
-            )
+            serializer.collect_seq(self.0.iter())
         }
     }
 
@@ -203,38 +216,63 @@ mod value_serde {
         where
             D: Deserializer<'de>,
         {
-            let vec: Vec<(CurrencySymbol, Assets)> = Vec::deserialize(deserializer)?;
+            let vec: Vec<(CurrencySymbol, Vec<(TokenName, BigInt)>)> =
+                Vec::deserialize(deserializer)?;
 
-            Ok(Value(
-                vec.into_iter().map(|(cs, assets)| (cs, assets.0)).collect(),
-            ))
+            Ok(vec
+                .into_iter()
+                .map(|(cs, tokens)| (cs, tokens.into_iter().collect()))
+                .collect::<BTreeMap<_, BTreeMap<_, _>>>()
+                .into())
         }
     }
+}
 
-    impl Serialize for Assets {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            serializer.collect_seq(self.0.iter())
-        }
+#[cfg(feature = "lbf")]
+impl Json for Value {
+    fn to_json(&self) -> serde_json::Value {
+        BTreeMap::from(self.clone()).to_json()
     }
 
-    impl<'de> Deserialize<'de> for Assets {
-        fn deserialize<D>(deserializer: D) -> Result<Assets, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let vec: Vec<(TokenName, BigInt)> = Vec::deserialize(deserializer)?;
+    fn from_json(value: &serde_json::Value) -> Result<Self, Error> {
+        BTreeMap::from_json(value).map(Value::from)
+    }
+}
 
-            Ok(Assets(vec.into_iter().collect()))
-        }
+impl From<BTreeMap<CurrencySymbol, BTreeMap<TokenName, BigInt>>> for Value {
+    fn from(map: BTreeMap<CurrencySymbol, BTreeMap<TokenName, BigInt>>) -> Self {
+        Value(
+            map.into_iter()
+                .map(|(cs, tokens)| (cs, tokens.into_iter().collect()))
+                .collect(),
+        )
+    }
+}
+
+impl From<Value> for BTreeMap<CurrencySymbol, BTreeMap<TokenName, BigInt>> {
+    fn from(value: Value) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(cs, tokens)| (cs, tokens.into_iter().collect()))
+            .collect()
+    }
+}
+
+/// Collect token quantities into a value, adding up the quantities of the same token
+impl FromIterator<(CurrencySymbol, TokenName, BigInt)> for Value {
+    fn from_iter<I: IntoIterator<Item = (CurrencySymbol, TokenName, BigInt)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Value::new(), |mut value, (cs, tn, amount)| {
+                value.add_token_mut(&cs, &tn, &amount);
+                value
+            })
     }
 }
 
 impl Value {
     pub fn new() -> Self {
-        Value(BTreeMap::new())
+        Value(Vec::new())
     }
     /// Create a Value containing only ada tokens, given the quantity in lovelace.
     pub fn ada_value(amount: &BigInt) -> Self {
@@ -243,18 +281,12 @@ impl Value {
 
     /// Create a Value containing only the given quantity of the given token.
     pub fn token_value(cs: &CurrencySymbol, tn: &TokenName, amount: &BigInt) -> Self {
-        Value(singleton((
-            cs.clone(),
-            singleton((tn.clone(), amount.clone())),
-        )))
+        Value(vec![(cs.clone(), vec![(tn.clone(), amount.clone())])])
     }
 
     /// Lookup the quantity of the given token.
     pub fn get_token_amount(&self, cs: &CurrencySymbol, tn: &TokenName) -> BigInt {
-        self.0
-            .get(cs)
-            .and_then(|tn_map| tn_map.get(tn))
-            .map_or(BigInt::zero(), Clone::clone)
+        self.lookup(cs, tn).map_or(BigInt::zero(), Clone::clone)
     }
 
     /// Lookup the quantity of ada(unit: lovelace).
@@ -264,21 +296,45 @@ impl Value {
 
     /// Insert a new token into the value, or replace the existing quantity.
     pub fn insert_token(&self, cs: &CurrencySymbol, tn: &TokenName, a: &BigInt) -> Self {
-        let mut result_map = self.0.clone();
-
-        result_map
-            .entry(cs.clone())
-            .and_modify(|tn_map| {
-                tn_map
-                    .entry(tn.clone())
-                    .and_modify(|old_a| {
-                        old_a.clone_from(a);
-                    })
-                    .or_insert_with(|| a.clone());
-            })
-            .or_insert_with(|| singleton((tn.clone(), a.clone())));
+        let mut result = self.clone();
+        result.insert_token_mut(cs, tn, a);
+        result
+    }
+
+    /// Insert a new token into the value in place, or replace the existing quantity.
+    pub fn insert_token_mut(&mut self, cs: &CurrencySymbol, tn: &TokenName, a: &BigInt) {
+        self.amount_mut(cs, tn).clone_from(a);
+    }
+
+    /// Add a quantity to a token of the value in place. Missing tokens are inserted.
+    pub fn add_token_mut(&mut self, cs: &CurrencySymbol, tn: &TokenName, a: &BigInt) {
+        *self.amount_mut(cs, tn) += a;
+    }
+
+    /// Mutable reference to the quantity of a token, inserting it with a zero quantity if missing
+    fn amount_mut(&mut self, cs: &CurrencySymbol, tn: &TokenName) -> &mut BigInt {
+        let tokens = get_or_insert_with(&mut self.0, cs, Vec::new);
+
+        get_or_insert_with(tokens, tn, BigInt::zero)
+    }
+
+    /// Iterate over the tokens of the value, ordered by currency symbol and token name
+    pub fn iter(&self) -> impl Iterator<Item = (&CurrencySymbol, &TokenName, &BigInt)> {
+        self.0
+            .iter()
+            .flat_map(|(cs, tokens)| tokens.iter().map(move |(tn, amount)| (cs, tn, amount)))
+    }
+
+    /// Currency symbols of the value, in order
+    pub fn currency_symbols(&self) -> impl Iterator<Item = &CurrencySymbol> {
+        self.0.iter().map(|(cs, _)| cs)
+    }
 
-        Self(result_map)
+    /// Tokens of a currency symbol with their quantities, ordered by token name
+    pub fn tokens(&self, cs: &CurrencySymbol) -> impl Iterator<Item = (&TokenName, &BigInt)> {
+        get(&self.0, cs)
+            .into_iter()
+            .flat_map(|tokens| tokens.iter().map(|(tn, amount)| (tn, amount)))
     }
 
     /// Return true if the value don't have any entries.
@@ -299,6 +355,104 @@ impl Value {
             .is_empty()
     }
 
+    /// Combine two values, applying `f` to the quantities of each token present in either of them.
+    /// A token missing from one of the values is treated as having a zero quantity.
+    ///
+    /// Same as `unionWith` from `PlutusLedgerApi.V1.Value`.
+    pub fn union_with<F>(&self, other: &Value, mut f: F) -> Self
+    where
+        F: FnMut(&BigInt, &BigInt) -> BigInt,
+    {
+        Value(merge_with(
+            self.0.clone(),
+            other.0.clone(),
+            |tokens, other_tokens| {
+                merge_with(
+                    tokens.unwrap_or_default(),
+                    other_tokens.unwrap_or_default(),
+                    |a, b| f(&a.unwrap_or_default(), &b.unwrap_or_default()),
+                )
+            },
+        ))
+    }
+
+    /// Check whether every token quantity of this value is greater than or equal to the
+    /// corresponding quantity in `other`, treating missing tokens as zero.
+    pub fn geq(&self, other: &Value) -> bool {
+        self.check_bin_rel(other, |a, b| a >= b)
+    }
+
+    /// Check whether every token quantity of this value is less than or equal to the
+    /// corresponding quantity in `other`, treating missing tokens as zero.
+    pub fn leq(&self, other: &Value) -> bool {
+        self.check_bin_rel(other, |a, b| a <= b)
+    }
+
+    /// Check whether every token quantity of this value is strictly greater than the
+    /// corresponding quantity in `other`, treating missing tokens as zero.
+    ///
+    /// Two zero values are not strictly greater than each other.
+    pub fn gt(&self, other: &Value) -> bool {
+        !(self.is_zero_amount() && other.is_zero_amount())
+            && self.check_bin_rel(other, |a, b| a > b)
+    }
+
+    /// Check whether every token quantity of this value is strictly less than the
+    /// corresponding quantity in `other`, treating missing tokens as zero.
+    ///
+    /// Two zero values are not strictly less than each other.
+    pub fn lt(&self, other: &Value) -> bool {
+        !(self.is_zero_amount() && other.is_zero_amount())
+            && self.check_bin_rel(other, |a, b| a < b)
+    }
+
+    /// Pointwise maximum of two values (join)
+    pub fn max(&self, other: &Value) -> Self {
+        self.union_with(other, |a, b| std::cmp::max(a, b).clone())
+    }
+
+    /// Pointwise minimum of two values (meet)
+    pub fn min(&self, other: &Value) -> Self {
+        self.union_with(other, |a, b| std::cmp::min(a, b).clone())
+    }
+
+    /// Split a value into its negative and positive parts. The negative part is negated, so both
+    /// of the resulting values only contain positive quantities. Zero quantities are dropped.
+    ///
+    /// The original value (without zero entries) can be restored as `positive - negative`.
+    pub fn split(&self) -> (Self, Self) {
+        (
+            self.clone().filter(|_, _, a| a.is_negative()).neg(),
+            self.clone().filter(|_, _, a| a.is_positive()),
+        )
+    }
+
+    /// Check a binary relation on the quantities of each token present in either of the values,
+    /// treating missing tokens as zero.
+    fn check_bin_rel<F>(&self, other: &Value, f: F) -> bool
+    where
+        F: Fn(&BigInt, &BigInt) -> bool,
+    {
+        let zero = BigInt::zero();
+
+        self.iter()
+            .all(|(cs, tn, a)| f(a, other.lookup(cs, tn).unwrap_or(&zero)))
+            && other
+                .iter()
+                .filter(|(cs, tn, _)| self.lookup(cs, tn).is_none())
+                .all(|(_, _, b)| f(&zero, b))
+    }
+
+    /// Check whether all quantities are zero (this is also true for an empty value).
+    fn is_zero_amount(&self) -> bool {
+        self.iter().all(|(_, _, amount)| amount.is_zero())
+    }
+
+    /// Lookup the quantity of the given token, without defaulting to zero
+    fn lookup(&self, cs: &CurrencySymbol, tn: &TokenName) -> Option<&BigInt> {
+        get(&self.0, cs).and_then(|tokens| get(tokens, tn))
+    }
+
     pub fn is_pure_ada(&self) -> bool {
         self.0.iter().all(|(cs, _)| cs == &CurrencySymbol::Ada)
     }
@@ -331,16 +485,16 @@ impl Value {
         Value(
             (self.0)
                 .into_iter()
-                .filter_map(|(cs, tn_map)| {
-                    let filtered_tn_map = tn_map
+                .filter_map(|(cs, tokens)| {
+                    let filtered_tokens = tokens
                         .into_iter()
                         .filter_map(|(tn, a)| f(&cs, &tn, &a).map(|a| (tn, a)))
-                        .collect::<BTreeMap<TokenName, BigInt>>();
+                        .collect::<Tokens>();
 
-                    if filtered_tn_map.is_empty() {
+                    if filtered_tokens.is_empty() {
                         None
                     } else {
-                        Some((cs.clone(), filtered_tn_map))
+                        Some((cs, filtered_tokens))
                     }
                 })
                 .collect(),
@@ -350,33 +504,139 @@ impl Value {
     /// Create a vector with each distinct value
     /// Warning: is the value is not normalized, the same asset class can appear twice
     pub fn flatten(&self) -> Vec<(&CurrencySymbol, &TokenName, &BigInt)> {
-        self.0
-            .iter()
-            .flat_map(|(currency_symbol, assets)| {
-                assets
-                    .iter()
-                    .map(move |(token_name, amount)| (currency_symbol, token_name, amount))
-            })
-            .collect()
+        self.iter().collect()
     }
 
     pub fn unflatten(list: &[(CurrencySymbol, TokenName, BigInt)]) -> Self {
-        list.iter()
-            .fold(Value::new(), |v, (cs, tn, am)| v.insert_token(cs, tn, am))
+        list.iter().fold(Value::new(), |mut v, (cs, tn, am)| {
+            v.insert_token_mut(cs, tn, am);
+            v
+        })
+    }
+
+    /// Size of the value in bytes, when CBOR serialized as the value of a transaction output
+    pub fn serialized_size(&self) -> usize {
+        self.serialized_size_with_coin(&self.get_ada_amount())
+    }
+
+    /// Size of the CBOR serialized value, using `coin` as its Lovelace amount
+    pub(crate) fn serialized_size_with_coin(&self, coin: &BigInt) -> usize {
+        let coin_size = integer_size(coin);
+
+        let multi_asset = self
+            .0
+            .iter()
+            .filter(|(cs, _)| !cs.is_ada())
+            .collect::<Vec<_>>();
+
+        if multi_asset.is_empty() {
+            coin_size
+        } else {
+            1 + coin_size
+                + head_size(multi_asset.len() as u64)
+                + multi_asset
+                    .into_iter()
+                    .map(|(cs, tokens)| {
+                        let policy_len = match cs {
+                            CurrencySymbol::Ada => 0,
+                            CurrencySymbol::NativeToken(MintingPolicyHash(ScriptHash(
+                                LedgerBytes(bytes),
+                            ))) => bytes.len(),
+                        };
+
+                        bytes_size(policy_len)
+                            + head_size(tokens.len() as u64)
+                            + tokens
+                                .iter()
+                                .map(|(TokenName(LedgerBytes(name)), amount)| {
+                                    bytes_size(name.len()) + integer_size(amount)
+                                })
+                                .sum::<usize>()
+                    })
+                    .sum::<usize>()
+        }
+    }
+
+    /// Partition the value into multiple values, each of them holding at most `max_assets` native
+    /// tokens and fitting into `max_value_size` bytes when serialized. Tokens of the same currency
+    /// symbol are kept together where possible, and the Ada amount is kept in the first value.
+    ///
+    /// Sizes are calculated with the largest possible Lovelace amount, so the values still fit
+    /// after being topped up with the minimum Ada of a transaction output.
+    ///
+    /// The original value can be restored with `Value::merge`.
+    pub fn partition(
+        &self,
+        max_value_size: usize,
+        max_assets: usize,
+    ) -> Result<Vec<Value>, ValuePartitionError> {
+        let max_coin = BigInt::from(u64::MAX);
+        let fits = |value: &Value| {
+            value.native_token_count() <= max_assets
+                && value.serialized_size_with_coin(&max_coin) <= max_value_size
+        };
+
+        let mut parts = vec![self.clone().filter(|cs, _, _| cs.is_ada())];
+
+        for (cs, tokens) in self.0.iter().filter(|(cs, _)| !cs.is_ada()) {
+            let current = parts.last_mut().unwrap();
+
+            let mut with_group = current.clone();
+            *get_or_insert_with(&mut with_group.0, cs, Vec::new) = tokens.clone();
+            if fits(&with_group) {
+                *current = with_group;
+                continue;
+            }
+
+            let group = Value(vec![(cs.clone(), tokens.clone())]);
+            if fits(&group) {
+                parts.push(group);
+                continue;
+            }
+
+            // The tokens of this currency symbol don't fit into a single value, so they are
+            // distributed one by one
+            for (cs, tn, amount) in group.flatten() {
+                let current = parts.last_mut().unwrap();
+
+                let with_token = current.insert_token(cs, tn, amount);
+                if fits(&with_token) {
+                    *current = with_token;
+                    continue;
+                }
+
+                let token = Value::token_value(cs, tn, amount);
+                if !fits(&token) {
+                    return Err(ValuePartitionError::AssetTooLarge(AssetClass {
+                        currency_symbol: cs.clone(),
+                        token_name: tn.clone(),
+                    }));
+                }
+                parts.push(token);
+            }
+        }
+
+        Ok(parts)
+    }
+
+    /// Merge multiple values into one, reversing `Value::partition`
+    pub fn merge<'a>(values: impl IntoIterator<Item = &'a Value>) -> Self {
+        values.into_iter().sum()
+    }
+
+    /// Number of native tokens in the value, not counting Ada
+    fn native_token_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(cs, _)| !cs.is_ada())
+            .map(|(_, tokens)| tokens.len())
+            .sum()
     }
 }
 
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut it = self
-            .0
-            .iter()
-            .flat_map(|(currency_symbol, assets)| {
-                assets
-                    .iter()
-                    .map(move |(token_name, amount)| (currency_symbol, token_name, amount))
-            })
-            .peekable();
+        let mut it = self.iter().peekable();
         while let Some((cur_sym, tn, amount)) = it.next() {
             if cur_sym.is_ada() {
                 amount.fmt(f)?;
@@ -442,6 +702,96 @@ impl FromStr for Value {
     }
 }
 
+impl Value {
+    /// Parse a value in the syntax of cardano-cli `--tx-out` and `--mint` arguments
+    ///
+    /// Entries are divided by `+` signs with optional spaces around them, and the whole value can
+    /// be surrounded by double quotes. Quantities can be negative (for minting), and are followed
+    /// by `lovelace`, an asset class with a hexadecimal token name, or nothing (for Lovelace).
+    /// Quantities of the same asset class are added together.
+    /// E.g.: "2000000 lovelace + 5 11223344556677889900112233445566778899001122334455667788.001122"
+    pub fn from_cli_str(s: &str) -> Result<Self, ConversionError> {
+        all_consuming(cli_value)(s)
+            .finish()
+            .map_err(|err| {
+                ConversionError::ParseError(anyhow!(
+                    "Error while parsing cardano-cli Value '{}': {}",
+                    s,
+                    err
+                ))
+            })
+            .map(|(_, value)| value)
+    }
+}
+
+/// Nom parser for the asset of a cardano-cli value entry
+/// Expects `lovelace`, or a currency symbol optionally followed by a `.` and a token name, both in
+/// hexadecimal format
+fn cli_asset_class(input: &str) -> IResult<&str, AssetClass, VerboseError<&str>> {
+    alt((
+        map(tag("lovelace"), |_| AssetClass {
+            currency_symbol: CurrencySymbol::Ada,
+            token_name: TokenName::ada(),
+        }),
+        map(
+            tuple((
+                verify(currency_symbol, |cs| !cs.is_ada()),
+                opt(preceded(char('.'), token_name)),
+            )),
+            |(currency_symbol, token_name)| AssetClass {
+                currency_symbol,
+                token_name: token_name.unwrap_or_else(TokenName::ada),
+            },
+        ),
+    ))(input)
+}
+
+/// Nom parser for a single entry of a cardano-cli value
+/// Expects an integer quantity, optionally followed by an asset after whitespace
+/// E.g.: -5 11223344556677889900112233445566778899001122334455667788.001122
+fn cli_flat_value(input: &str) -> IResult<&str, (AssetClass, BigInt), VerboseError<&str>> {
+    map(
+        tuple((big_int, opt(preceded(space1, cli_asset_class)))),
+        |(amount, asset_class)| {
+            (
+                asset_class.unwrap_or(AssetClass {
+                    currency_symbol: CurrencySymbol::Ada,
+                    token_name: TokenName::ada(),
+                }),
+                amount,
+            )
+        },
+    )(input)
+}
+
+/// Nom parser for a cardano-cli value, optionally surrounded by double quotes
+/// Expects cardano-cli value entries divided by a `+` sign
+/// E.g.: "2000000 lovelace + 5 11223344556677889900112233445566778899001122334455667788.001122"
+pub(crate) fn cli_value(input: &str) -> IResult<&str, Value, VerboseError<&str>> {
+    let entries = || {
+        map(
+            separated_list1(tuple((space0, char('+'), space0)), cli_flat_value),
+            |entries| {
+                entries
+                    .into_iter()
+                    .fold(Value::new(), |mut value, (asset_class, amount)| {
+                        value.add_token_mut(
+                            &asset_class.currency_symbol,
+                            &asset_class.token_name,
+                            &amount,
+                        );
+                        value
+                    })
+            },
+        )
+    };
+
+    context(
+        "cardano-cli value",
+        alt((delimited(char('"'), entries(), char('"')), entries())),
+    )(input)
+}
+
 impl Zero for Value {
     fn zero() -> Self {
         Default::default()
@@ -452,27 +802,54 @@ impl Zero for Value {
     }
 }
 
-impl_op!(+ |a: &Value, b: &Value| -> Value { a.clone() + b.clone() });
-impl_op!(+ |a: &Value, b: Value| -> Value { a.clone() + b });
-impl_op!(+ |a: Value, b: &Value| -> Value { a + b.clone() });
+impl_op!(+ |a: &Value, b: &Value| -> Value { a.clone() + b });
+impl_op!(+ |a: &Value, b: Value| -> Value { b + a });
+impl_op!(+ |a: Value, b: &Value| -> Value {
+    let mut a = a;
+    a += b;
+    a
+});
 
 impl Add<Value> for Value {
     type Output = Value;
 
-    fn add(self, rhs: Value) -> Self::Output {
-        Value(union_btree_maps_with(
-            |lhs, rhs| union_btree_maps_with(|lhs, rhs| lhs + rhs, lhs, rhs),
-            self.0,
-            rhs.0,
-        ))
+    fn add(mut self, rhs: Value) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Merges the tokens of `rhs` into the value, moving them instead of cloning
+impl AddAssign<Value> for Value {
+    fn add_assign(&mut self, rhs: Value) {
+        self.0 = merge_with(std::mem::take(&mut self.0), rhs.0, |tokens, rhs_tokens| {
+            merge_with(
+                tokens.unwrap_or_default(),
+                rhs_tokens.unwrap_or_default(),
+                |a, b| a.unwrap_or_default() + b.unwrap_or_default(),
+            )
+        });
+    }
+}
+
+/// Adds the tokens of `rhs` one by one, only cloning the keys of tokens missing from the value
+impl AddAssign<&Value> for Value {
+    fn add_assign(&mut self, rhs: &Value) {
+        for (cs, tn, a) in rhs.iter() {
+            self.add_token_mut(cs, tn, a);
+        }
     }
 }
 
 impl Neg for Value {
     type Output = Value;
 
-    fn neg(self) -> Self::Output {
-        self.map_amount(|_, _, a| a.neg())
+    fn neg(mut self) -> Self::Output {
+        self.0
+            .iter_mut()
+            .flat_map(|(_, tokens)| tokens.iter_mut())
+            .for_each(|(_, a)| *a = -std::mem::take(a));
+        self
     }
 }
 
@@ -484,15 +861,34 @@ impl Neg for &Value {
     }
 }
 
-impl_op!(-|a: &Value, b: &Value| -> Value { a.clone() - b.clone() });
+impl_op!(-|a: &Value, b: &Value| -> Value { a.clone() - b });
 impl_op!(-|a: &Value, b: Value| -> Value { a.clone() - b });
-impl_op!(-|a: Value, b: &Value| -> Value { a - b.clone() });
+impl_op!(-|a: Value, b: &Value| -> Value {
+    let mut a = a;
+    a -= b;
+    a
+});
 
 impl Sub<Value> for Value {
     type Output = Value;
 
-    fn sub(self, rhs: Value) -> Self::Output {
-        self.add(rhs.neg())
+    fn sub(mut self, rhs: Value) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl SubAssign<Value> for Value {
+    fn sub_assign(&mut self, rhs: Value) {
+        *self += -rhs;
+    }
+}
+
+impl SubAssign<&Value> for Value {
+    fn sub_assign(&mut self, rhs: &Value) {
+        for (cs, tn, a) in rhs.iter() {
+            *self.amount_mut(cs, tn) -= a;
+        }
     }
 }
 
@@ -518,10 +914,10 @@ impl Mul<&BigInt> for &Value {
         Value(
             self.0
                 .iter()
-                .map(|(cs, tn_map)| {
+                .map(|(cs, tokens)| {
                     (
                         cs.clone(),
-                        tn_map.iter().map(|(tn, q)| (tn.clone(), q * rhs)).collect(),
+                        tokens.iter().map(|(tn, q)| (tn.clone(), q * rhs)).collect(),
                     )
                 })
                 .collect(),
@@ -531,23 +927,44 @@ impl Mul<&BigInt> for &Value {
 
 impl Sum<Value> for Value {
     fn sum<I: Iterator<Item = Value>>(iter: I) -> Self {
-        iter.fold(Zero::zero(), Add::add)
+        iter.fold(Zero::zero(), |mut acc, value| {
+            acc += value;
+            acc
+        })
     }
 }
 
 impl<'a> Sum<&'a Value> for Value {
     fn sum<I: Iterator<Item = &'a Value>>(iter: I) -> Self {
-        iter.fold(Zero::zero(), Add::add)
+        iter.fold(Zero::zero(), |mut acc, value| {
+            acc += value;
+            acc
+        })
     }
 }
 
 impl IsPlutusData for Value {
     fn to_plutus_data(&self) -> PlutusData {
-        self.0.to_plutus_data()
+        PlutusData::Map(
+            self.0
+                .iter()
+                .map(|(cs, tokens)| {
+                    (
+                        cs.to_plutus_data(),
+                        PlutusData::Map(
+                            tokens
+                                .iter()
+                                .map(|(tn, amount)| (tn.to_plutus_data(), amount.to_plutus_data()))
+                                .collect(),
+                        ),
+                    )
+                })
+                .collect(),
+        )
     }
 
     fn from_plutus_data(data: &PlutusData) -> Result<Self, PlutusDataError> {
-        IsPlutusData::from_plutus_data(data).map(Self)
+        BTreeMap::from_plutus_data(data).map(Value::from)
     }
 }
 
@@ -579,7 +996,7 @@ impl TryFromPLA<BTreeMap<TokenName, BigInt>> for csl::Assets {
 impl FromCSL<csl::MultiAsset> for Value {
     fn from_csl(value: &csl::MultiAsset) -> Self {
         let keys = value.keys();
-        Value((0..keys.len()).fold(BTreeMap::new(), |mut acc, idx| {
+        Value::from((0..keys.len()).fold(BTreeMap::new(), |mut acc, idx| {
             let script_hash = keys.get(idx);
             if let Some(assets) = value.get(&script_hash) {
                 let assets = BTreeMap::from_csl(&assets);
@@ -607,20 +1024,25 @@ impl FromCSL<csl::Value> for Value {
 impl TryFromPLA<Value> for csl::Value {
     fn try_from_pla(val: &Value) -> Result<Self, TryFromPLAError> {
         let coin: csl::Coin = val
-            .0
-            .get(&CurrencySymbol::Ada)
-            .and_then(|m| m.get(&TokenName::ada()))
+            .lookup(&CurrencySymbol::Ada, &TokenName::ada())
             .map_or(Ok(csl::BigNum::zero()), TryToCSL::try_to_csl)?;
 
         let m_ass = val
             .0
             .iter()
-            .filter_map(|(cs, tn_map)| match &cs {
+            .filter_map(|(cs, tokens)| match &cs {
                 CurrencySymbol::Ada => None,
-                CurrencySymbol::NativeToken(h) => Some((h, tn_map)),
+                CurrencySymbol::NativeToken(h) => Some((h, tokens)),
             })
-            .try_fold(csl::MultiAsset::new(), |mut acc, (cs, ass)| {
-                acc.insert(&cs.try_to_csl()?, &ass.try_to_csl()?);
+            .try_fold(csl::MultiAsset::new(), |mut acc, (cs, tokens)| {
+                let assets = tokens.iter().try_fold(
+                    csl::Assets::new(),
+                    |mut assets, (tn, amount)| -> Result<_, TryFromPLAError> {
+                        assets.insert(&tn.try_to_csl()?, &amount.try_to_csl()?);
+                        Ok(assets)
+                    },
+                )?;
+                acc.insert(&cs.try_to_csl()?, &assets);
                 Ok(acc)
             })?;
 
@@ -670,7 +1092,7 @@ impl TryFromPLA<BTreeMap<TokenName, BigInt>> for csl::MintAssets {
 impl FromCSL<csl::Mint> for Value {
     fn from_csl(mint: &csl::Mint) -> Self {
         let keys = mint.keys();
-        Value(
+        Value::from(
             (0..keys.len())
                 .map(|idx| {
                     let sh = keys.get(idx);
@@ -685,6 +1107,68 @@ impl FromCSL<csl::Mint> for Value {
     }
 }
 
+/// Search a key in an association list sorted by its keys, returning its index, or the index at
+/// which it should be inserted
+fn search<K: Ord, V>(entries: &[(K, V)], key: &K) -> Result<usize, usize> {
+    entries.binary_search_by(|(k, _)| k.cmp(key))
+}
+
+fn get<'a, K: Ord, V>(entries: &'a [(K, V)], key: &K) -> Option<&'a V> {
+    search(entries, key).ok().map(|idx| &entries[idx].1)
+}
+
+/// Mutable reference to the value of a key in a sorted association list, inserting `default()` at
+/// its place if it is missing
+fn get_or_insert_with<'a, K: Ord + Clone, V>(
+    entries: &'a mut Vec<(K, V)>,
+    key: &K,
+    default: impl FnOnce() -> V,
+) -> &'a mut V {
+    let idx = search(entries, key).unwrap_or_else(|idx| {
+        entries.insert(idx, (key.clone(), default()));
+        idx
+    });
+
+    &mut entries[idx].1
+}
+
+/// Merge two association lists sorted by their keys in linear time, combining the values of each
+/// key with `f` (`None` stands for a key missing from one of the lists)
+fn merge_with<K: Ord, V, W>(
+    left: Vec<(K, V)>,
+    right: Vec<(K, V)>,
+    mut f: impl FnMut(Option<V>, Option<V>) -> W,
+) -> Vec<(K, W)> {
+    let mut merged = Vec::with_capacity(left.len().max(right.len()));
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    loop {
+        let ordering = match (left.peek(), right.peek()) {
+            (Some((l, _)), Some((r, _))) => l.cmp(r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return merged,
+        };
+
+        merged.push(match ordering {
+            Ordering::Less => {
+                let (k, l) = left.next().unwrap();
+                (k, f(Some(l), None))
+            }
+            Ordering::Greater => {
+                let (k, r) = right.next().unwrap();
+                (k, f(None, Some(r)))
+            }
+            Ordering::Equal => {
+                let (k, l) = left.next().unwrap();
+                let (_, r) = right.next().unwrap();
+                (k, f(Some(l), Some(r)))
+            }
+        });
+    }
+}
+
 ///////////////
 // TokenName //
 ///////////////
@@ -727,6 +1211,105 @@ impl TokenName {
     pub fn try_into_string(self) -> Result<String, std::string::FromUtf8Error> {
         String::from_utf8(self.0 .0)
     }
+
+    /// Construct a token name from a CIP-67 label and the rest of the name (up to 28 bytes)
+    pub fn with_label(label: u16, body: &[u8]) -> Result<Self, ConversionError> {
+        let [hi, lo] = label.to_be_bytes();
+        let checksum = crc8(&[hi, lo]);
+
+        let prefix = [
+            hi >> 4,
+            (hi << 4) | (lo >> 4),
+            (lo << 4) | (checksum >> 4),
+            checksum << 4,
+        ];
+
+        TokenName::from_bytes(prefix.iter().chain(body).copied().collect())
+    }
+
+    /// Split the token name into its CIP-67 label and the rest of the name, verifying the
+    /// label checksum
+    pub fn label(&self) -> Result<(u16, &[u8]), Cip67LabelError> {
+        let bytes = &self.0 .0;
+
+        let (prefix, body) = if bytes.len() >= 4 {
+            bytes.split_at(4)
+        } else {
+            return Err(Cip67LabelError::MissingLabel);
+        };
+
+        if prefix[0] >> 4 != 0 || prefix[3] & 0x0f != 0 {
+            return Err(Cip67LabelError::InvalidBrackets);
+        }
+
+        let label = (u16::from(prefix[0]) << 12)
+            | (u16::from(prefix[1]) << 4)
+            | (u16::from(prefix[2]) >> 4);
+        let checksum = (prefix[2] << 4) | (prefix[3] >> 4);
+        let expected = crc8(&label.to_be_bytes());
+
+        if checksum != expected {
+            return Err(Cip67LabelError::InvalidChecksum {
+                label,
+                expected,
+                got: checksum,
+            });
+        }
+
+        Ok((label, body))
+    }
+
+    /// CIP-68 reference NFT (label 100), holding the metadata datum
+    pub fn cip68_reference_nft(body: &[u8]) -> Result<Self, ConversionError> {
+        TokenName::with_label(CIP68_REFERENCE_NFT_LABEL, body)
+    }
+
+    /// CIP-68 user NFT (label 222)
+    pub fn cip68_nft(body: &[u8]) -> Result<Self, ConversionError> {
+        TokenName::with_label(CIP68_NFT_LABEL, body)
+    }
+
+    /// CIP-68 user fungible token (label 333)
+    pub fn cip68_ft(body: &[u8]) -> Result<Self, ConversionError> {
+        TokenName::with_label(CIP68_FT_LABEL, body)
+    }
+
+    /// CIP-68 user rich fungible token (label 444)
+    pub fn cip68_rft(body: &[u8]) -> Result<Self, ConversionError> {
+        TokenName::with_label(CIP68_RFT_LABEL, body)
+    }
+}
+
+/// CIP-67 label of CIP-68 reference NFTs
+pub const CIP68_REFERENCE_NFT_LABEL: u16 = 100;
+/// CIP-67 label of CIP-68 user NFTs
+pub const CIP68_NFT_LABEL: u16 = 222;
+/// CIP-67 label of CIP-68 user fungible tokens
+pub const CIP68_FT_LABEL: u16 = 333;
+/// CIP-67 label of CIP-68 user rich fungible tokens
+pub const CIP68_RFT_LABEL: u16 = 444;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Cip67LabelError {
+    #[error("Token name is shorter than a CIP-67 label")]
+    MissingLabel,
+    #[error("CIP-67 label must start and end with zero bits")]
+    InvalidBrackets,
+    #[error("Invalid checksum of CIP-67 label {label}: expected {expected:#04x}, got {got:#04x}")]
+    InvalidChecksum { label: u16, expected: u8, got: u8 },
+}
+
+/// CRC-8 checksum (polynomial 0x07) used by CIP-67 labels
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
 }
 
 impl FromStr for TokenName {
@@ -862,6 +1445,87 @@ pub(crate) fn asset_class(input: &str) -> IResult<&str, AssetClass, VerboseError
     ))
 }
 
+impl AssetClass {
+    /// CIP-14 fingerprint of the asset class, the blake2b-160 hash of its policy id and token name
+    pub fn fingerprint(&self) -> AssetFingerprint {
+        let policy_id: &[u8] = match &self.currency_symbol {
+            CurrencySymbol::Ada => &[],
+            CurrencySymbol::NativeToken(MintingPolicyHash(ScriptHash(LedgerBytes(bytes)))) => bytes,
+        };
+
+        let mut hash = vec![0; 20];
+        Blake2b::<160>::new()
+            .update(policy_id)
+            .update(&self.token_name.0 .0)
+            .finalize_at(&mut hash);
+
+        AssetFingerprint(LedgerBytes(hash))
+    }
+}
+
+//////////////////////
+// AssetFingerprint //
+//////////////////////
+
+/// CIP-14 fingerprint of an asset class, as used by wallets and explorers
+///
+/// The fingerprint is a hash, so the asset class cannot be recovered from it. It can still be used
+/// as a key to look up asset classes, see `AssetClass::fingerprint`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AssetFingerprint(pub LedgerBytes);
+
+impl AssetFingerprint {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ConversionError> {
+        Ok(AssetFingerprint(LedgerBytes(guard_bytes(
+            "AssetFingerprint",
+            bytes,
+            20,
+        )?)))
+    }
+}
+
+/// Serialize into a bech32 string with the `asset` prefix (e.g. asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3)
+impl fmt::Display for AssetFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = bech32::encode("asset", self.0 .0.to_base32()).map_err(|_| fmt::Error)?;
+
+        write!(f, "{}", encoded)
+    }
+}
+
+impl FromStr for AssetFingerprint {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hrp, data) = bech32::decode(s).map_err(|err| {
+            ConversionError::ParseError(anyhow!(
+                "Error while parsing AssetFingerprint '{}': {}",
+                s,
+                err
+            ))
+        })?;
+
+        if hrp != "asset" {
+            return Err(ConversionError::ParseError(anyhow!(
+                "Error while parsing AssetFingerprint '{}': expected prefix 'asset', got '{}'",
+                s,
+                hrp
+            )));
+        }
+
+        let bytes = Vec::<u8>::from_base32(&data).map_err(|err| {
+            ConversionError::ParseError(anyhow!(
+                "Error while parsing AssetFingerprint '{}': {}",
+                s,
+                err
+            ))
+        })?;
+
+        AssetFingerprint::from_bytes(bytes)
+    }
+}
+
 ////////////////
 // Lovelace //
 ////////////////
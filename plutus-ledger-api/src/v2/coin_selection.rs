@@ -0,0 +1,264 @@
+//! Coin selection algorithms described in [CIP-2](https://cips.cardano.org/cip/CIP-0002)
+//!
+//! Both algorithms work on multi-asset values: native tokens of the target value are selected
+//! first, one asset class at a time, followed by Ada.
+//!
+//! For deterministic results (e.g. in tests), use a seeded random number generator with
+//! Random-Improve, such as `rand::rngs::StdRng::seed_from_u64`.
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use rand::Rng;
+
+use super::transaction::{TransactionInput, TransactionOutput};
+use super::value::{AssetClass, CurrencySymbol, TokenName, Value};
+
+/// Result of a coin selection
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinSelection {
+    /// Selected UTxOs, in the order of selection
+    pub inputs: Vec<(TransactionInput, TransactionOutput)>,
+    /// Value of the selected UTxOs exceeding the target value
+    pub change: Value,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CoinSelectionError {
+    #[error("Insufficient balance in the available UTxOs, missing {0}")]
+    InsufficientBalance(Value),
+    #[error("Selection requires more than the maximum of {0} inputs")]
+    MaximumInputCountExceeded(usize),
+}
+
+/// Select UTxOs covering the `target` value, using the Largest-First algorithm.
+///
+/// For each asset class of the target, UTxOs holding the largest quantity of it are selected until
+/// it is covered. If the change holds any value, it will also include at least `min_change_ada`
+/// Lovelace, so it can be paid to a change output.
+pub fn largest_first(
+    utxos: &[(TransactionInput, TransactionOutput)],
+    target: &Value,
+    min_change_ada: &BigInt,
+    max_inputs: usize,
+) -> Result<CoinSelection, CoinSelectionError> {
+    let mut state = SelectionState::new(utxos, max_inputs);
+
+    for (asset_class, quantity) in target_assets(target) {
+        state.select_until(&asset_class, &quantity, largest_candidate)?;
+    }
+
+    state.select_min_change_ada(target, min_change_ada, largest_candidate)?;
+
+    Ok(state.finish(target))
+}
+
+/// Select UTxOs covering the `target` value, using the Random-Improve algorithm.
+///
+/// In the first phase, UTxOs holding each asset class of the target are selected randomly until
+/// it is covered. In the second phase, further random UTxOs are selected, as long as they bring the
+/// selected quantity closer to twice the target quantity, without exceeding three times of it, or
+/// the maximum number of inputs. If the change holds any value, it will also include at least
+/// `min_change_ada` Lovelace, so it can be paid to a change output.
+pub fn random_improve<R>(
+    utxos: &[(TransactionInput, TransactionOutput)],
+    target: &Value,
+    min_change_ada: &BigInt,
+    max_inputs: usize,
+    rng: &mut R,
+) -> Result<CoinSelection, CoinSelectionError>
+where
+    R: Rng + ?Sized,
+{
+    let mut state = SelectionState::new(utxos, max_inputs);
+    let assets = target_assets(target);
+
+    for (asset_class, quantity) in &assets {
+        state.select_until(asset_class, quantity, |candidates, _| {
+            rng.gen_range(0..candidates.len())
+        })?;
+    }
+
+    for (asset_class, quantity) in &assets {
+        state.improve(asset_class, quantity, rng);
+    }
+
+    state.select_min_change_ada(target, min_change_ada, |candidates, _| {
+        rng.gen_range(0..candidates.len())
+    })?;
+
+    Ok(state.finish(target))
+}
+
+/// Asset classes and positive quantities of the target value, native tokens first, Ada last
+fn target_assets(target: &Value) -> Vec<(AssetClass, BigInt)> {
+    let (ada, tokens): (Vec<_>, Vec<_>) = target
+        .flatten()
+        .into_iter()
+        .filter(|(_, _, quantity)| quantity.is_positive())
+        .map(|(currency_symbol, token_name, quantity)| {
+            (
+                AssetClass {
+                    currency_symbol: currency_symbol.clone(),
+                    token_name: token_name.clone(),
+                },
+                quantity.clone(),
+            )
+        })
+        .partition(|(asset_class, _)| asset_class.currency_symbol.is_ada());
+
+    tokens.into_iter().chain(ada).collect()
+}
+
+/// Pick the candidate holding the largest quantity of the asset class
+fn largest_candidate(candidates: &[usize], quantities: &[BigInt]) -> usize {
+    (0..candidates.len())
+        .max_by_key(|&idx| &quantities[idx])
+        .unwrap_or_default()
+}
+
+struct SelectionState<'a> {
+    available: Vec<&'a (TransactionInput, TransactionOutput)>,
+    selected: Vec<&'a (TransactionInput, TransactionOutput)>,
+    selected_value: Value,
+    max_inputs: usize,
+}
+
+impl<'a> SelectionState<'a> {
+    fn new(utxos: &'a [(TransactionInput, TransactionOutput)], max_inputs: usize) -> Self {
+        SelectionState {
+            available: utxos.iter().collect(),
+            selected: Vec::new(),
+            selected_value: Value::new(),
+            max_inputs,
+        }
+    }
+
+    fn selected_quantity(&self, asset_class: &AssetClass) -> BigInt {
+        self.selected_value
+            .get_token_amount(&asset_class.currency_symbol, &asset_class.token_name)
+    }
+
+    /// Indices of the available UTxOs holding the asset class, with their quantities
+    fn candidates(&self, asset_class: &AssetClass) -> (Vec<usize>, Vec<BigInt>) {
+        self.available
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, output))| {
+                (
+                    idx,
+                    output
+                        .value
+                        .get_token_amount(&asset_class.currency_symbol, &asset_class.token_name),
+                )
+            })
+            .filter(|(_, quantity)| quantity.is_positive())
+            .unzip()
+    }
+
+    /// Move an available UTxO into the selection
+    fn select(&mut self, idx: usize) -> Result<(), CoinSelectionError> {
+        if self.selected.len() >= self.max_inputs {
+            return Err(CoinSelectionError::MaximumInputCountExceeded(
+                self.max_inputs,
+            ));
+        }
+
+        let utxo = self.available.swap_remove(idx);
+        self.selected_value += &utxo.1.value;
+        self.selected.push(utxo);
+
+        Ok(())
+    }
+
+    /// Select UTxOs until the selected quantity of the asset class reaches `quantity`.
+    /// `pick` chooses one of the candidates, given their quantities.
+    fn select_until<F>(
+        &mut self,
+        asset_class: &AssetClass,
+        quantity: &BigInt,
+        mut pick: F,
+    ) -> Result<(), CoinSelectionError>
+    where
+        F: FnMut(&[usize], &[BigInt]) -> usize,
+    {
+        loop {
+            let selected_quantity = self.selected_quantity(asset_class);
+            if &selected_quantity >= quantity {
+                return Ok(());
+            }
+
+            let (candidates, quantities) = self.candidates(asset_class);
+            if candidates.is_empty() {
+                return Err(CoinSelectionError::InsufficientBalance(Value::token_value(
+                    &asset_class.currency_symbol,
+                    &asset_class.token_name,
+                    &(quantity - selected_quantity),
+                )));
+            }
+
+            let idx = candidates[pick(&candidates, &quantities)];
+            self.select(idx)?;
+        }
+    }
+
+    /// Improvement phase of Random-Improve for a single asset class
+    fn improve<R>(&mut self, asset_class: &AssetClass, quantity: &BigInt, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let ideal: BigInt = quantity * 2;
+        let maximum: BigInt = quantity * 3;
+
+        while self.selected.len() < self.max_inputs {
+            let (candidates, quantities) = self.candidates(asset_class);
+            if candidates.is_empty() {
+                return;
+            }
+
+            let pick = rng.gen_range(0..candidates.len());
+            let selected_quantity = self.selected_quantity(asset_class);
+            let new_quantity = &selected_quantity + &quantities[pick];
+
+            let is_improvement =
+                (&ideal - &new_quantity).abs() < (&ideal - &selected_quantity).abs();
+
+            if !is_improvement || new_quantity > maximum || self.select(candidates[pick]).is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Make sure a non-empty change holds at least `min_change_ada` Lovelace
+    fn select_min_change_ada<F>(
+        &mut self,
+        target: &Value,
+        min_change_ada: &BigInt,
+        pick: F,
+    ) -> Result<(), CoinSelectionError>
+    where
+        F: FnMut(&[usize], &[BigInt]) -> usize,
+    {
+        let change = self.change(target);
+
+        if change.is_zero() || change.get_ada_amount() >= *min_change_ada {
+            return Ok(());
+        }
+
+        let ada = AssetClass {
+            currency_symbol: CurrencySymbol::Ada,
+            token_name: TokenName::ada(),
+        };
+
+        self.select_until(&ada, &(target.get_ada_amount() + min_change_ada), pick)
+    }
+
+    fn change(&self, target: &Value) -> Value {
+        (&self.selected_value - target).normalize()
+    }
+
+    fn finish(self, target: &Value) -> CoinSelection {
+        CoinSelection {
+            change: self.change(target),
+            inputs: self.selected.into_iter().cloned().collect(),
+        }
+    }
+}
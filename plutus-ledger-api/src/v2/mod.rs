@@ -1,6 +1,7 @@
 //! Plutus types and utilities for Plutus V2
 //!
 //! Types and utilities unchanged in the new version are re-exported from the v1 module.
+pub mod coin_selection;
 pub mod datum;
 pub mod transaction;
 
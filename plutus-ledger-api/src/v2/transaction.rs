@@ -10,24 +10,30 @@ use num_bigint::BigInt;
 use serde::{Deserialize, Serialize};
 
 use crate as plutus_ledger_api;
+use crate::cbor::{bytes_size, plutus_data_size};
 use crate::csl::csl_to_pla::{FromCSL, TryFromCSL, TryFromCSLError, TryToPLA};
 use crate::csl::pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL};
+use crate::keys::witness::{verify_witnesses, VKeyWitness, WitnessError};
+use crate::network::Network;
 use crate::plutus_data::IsPlutusData;
 #[cfg(feature = "chrono")]
 pub use crate::v1::transaction::POSIXTimeConversionError;
 pub use crate::v1::transaction::{
-    DCert, POSIXTime, POSIXTimeRange, ScriptPurpose, TransactionHash, TransactionInput,
+    parse_cli_tx_out, DCert, POSIXTime, POSIXTimeRange, ScriptPurpose, TransactionHash,
+    TransactionInput,
 };
 
-use super::address::AddressWithExtraInfo;
 use super::{
-    address::{Address, RewardAddressWithExtraInfo, StakingCredential},
+    address::{
+        Address, AddressError, ChainPointer, Credential, LedgerAddress, RewardAddress,
+        StakingCredential,
+    },
     assoc_map::AssocMap,
-    crypto::PaymentPubKeyHash,
+    crypto::{Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash},
     datum::{Datum, DatumHash, OutputDatum},
     redeemer::Redeemer,
-    script::ScriptHash,
-    value::Value,
+    script::{PlutusScript, Script, ScriptHash, ValidatorHash},
+    value::{CurrencySymbol, TokenName, Value},
 };
 
 ///////////////////////
@@ -51,7 +57,127 @@ pub struct TransactionOutput {
 
 impl TryFromCSL<csl::TransactionOutput> for TransactionOutput {
     fn try_from_csl(value: &csl::TransactionOutput) -> Result<Self, TryFromCSLError> {
+        LedgerTransactionOutput::try_from_csl(value)?
+            .try_into()
+            .map_err(|err: AddressError| TryFromCSLError::ImpossibleConversion(err.to_string()))
+    }
+}
+
+impl TryFromCSL<csl::TransactionOutputs> for Vec<TransactionOutput> {
+    fn try_from_csl(value: &csl::TransactionOutputs) -> Result<Self, TryFromCSLError> {
+        (0..value.len())
+            .map(|idx| TransactionOutput::try_from_csl(&value.get(idx)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionOutputWithExtraInfo<'a> {
+    pub transaction_output: &'a TransactionOutput,
+    pub scripts: &'a BTreeMap<ScriptHash, Script>,
+    pub network: &'a Network,
+    pub data_cost: &'a csl::DataCost,
+}
+
+impl<'a> TransactionOutputWithExtraInfo<'a> {
+    pub fn new(
+        transaction_output: &'a TransactionOutput,
+        scripts: &'a BTreeMap<ScriptHash, Script>,
+        network: &'a Network,
+        data_cost: &'a csl::DataCost,
+    ) -> Self {
+        TransactionOutputWithExtraInfo {
+            transaction_output,
+            scripts,
+            network,
+            data_cost,
+        }
+    }
+}
+
+impl TryFromPLA<TransactionOutputWithExtraInfo<'_>> for csl::TransactionOutput {
+    fn try_from_pla(val: &TransactionOutputWithExtraInfo<'_>) -> Result<Self, TryFromPLAError> {
+        let output = val.transaction_output;
+        let reference_script = output
+            .reference_script
+            .as_ref()
+            .map(|script_hash| {
+                val.scripts
+                    .get(script_hash)
+                    .ok_or_else(|| TryFromPLAError::MissingScript(script_hash.clone()))
+            })
+            .transpose()?;
+
+        csl_transaction_output(
+            output.address.with_extra_info(val.network).try_to_csl()?,
+            &output.value,
+            &output.datum,
+            reference_script,
+            val.data_cost,
+        )
+    }
+}
+
+/////////////////////////////
+// LedgerTransactionOutput //
+/////////////////////////////
+
+/// An output of a transaction, as it is stored in the UTxO set
+///
+/// Unlike `TransactionOutput`, its address can be a Byron bootstrap address. Converting it into a
+/// `TransactionOutput` or a `TxInInfo` fails with `AddressError::ByronAddress` for these outputs.
+/// Its reference script is stored in full, and only its hash is kept in a `TransactionOutput`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LedgerTransactionOutput {
+    pub address: LedgerAddress,
+    pub value: Value,
+    pub datum: OutputDatum,
+    pub reference_script: Option<Script>,
+}
+
+impl LedgerTransactionOutput {
+    /// Ledger output of a `TransactionOutput`, with its reference script looked up from `scripts`
+    /// by its hash
+    pub fn from_output(
+        output: TransactionOutput,
+        scripts: &BTreeMap<ScriptHash, Script>,
+    ) -> Result<Self, TryFromPLAError> {
+        let reference_script = output
+            .reference_script
+            .map(|script_hash| {
+                scripts
+                    .get(&script_hash)
+                    .cloned()
+                    .ok_or(TryFromPLAError::MissingScript(script_hash))
+            })
+            .transpose()?;
+
+        Ok(LedgerTransactionOutput {
+            address: output.address.into(),
+            value: output.value,
+            datum: output.datum,
+            reference_script,
+        })
+    }
+}
+
+impl TryFrom<LedgerTransactionOutput> for TransactionOutput {
+    type Error = AddressError;
+
+    fn try_from(output: LedgerTransactionOutput) -> Result<Self, Self::Error> {
         Ok(TransactionOutput {
+            address: output.address.try_into()?,
+            value: output.value,
+            datum: output.datum,
+            reference_script: output.reference_script.as_ref().map(Script::hash),
+        })
+    }
+}
+
+impl TryFromCSL<csl::TransactionOutput> for LedgerTransactionOutput {
+    fn try_from_csl(value: &csl::TransactionOutput) -> Result<Self, TryFromCSLError> {
+        Ok(LedgerTransactionOutput {
             address: value.address().try_to_pla()?,
             datum: if value.has_data_hash() {
                 OutputDatum::DatumHash(DatumHash::from_csl(&value.data_hash().unwrap()))
@@ -60,106 +186,267 @@ impl TryFromCSL<csl::TransactionOutput> for TransactionOutput {
             } else {
                 OutputDatum::None
             },
-            reference_script: if value.has_script_ref() {
-                let script_ref = value.script_ref().unwrap();
-                let script_hash = if script_ref.is_native_script() {
-                    script_ref.native_script().unwrap().hash()
-                } else {
-                    script_ref.plutus_script().unwrap().hash()
-                };
-                Some(ScriptHash::from_csl(&script_hash))
-            } else {
-                None
-            },
+            reference_script: value.script_ref().as_ref().map(Script::from_csl),
             value: Value::from_csl(&value.amount()),
         })
     }
 }
 
-impl TryFromCSL<csl::TransactionOutputs> for Vec<TransactionOutput> {
+impl TryFromCSL<csl::TransactionOutputs> for Vec<LedgerTransactionOutput> {
     fn try_from_csl(value: &csl::TransactionOutputs) -> Result<Self, TryFromCSLError> {
         (0..value.len())
-            .map(|idx| TransactionOutput::try_from_csl(&value.get(idx)))
+            .map(|idx| LedgerTransactionOutput::try_from_csl(&value.get(idx)))
             .collect()
     }
 }
 
+/// Ledger transaction output with the information needed to convert it to CSL
+///
+/// The network is only used for Shelley addresses.
 #[derive(Clone, Debug)]
-pub struct TransactionOutputWithExtraInfo<'a> {
-    pub transaction_output: &'a TransactionOutput,
-    pub scripts: &'a BTreeMap<ScriptHash, csl::PlutusScript>,
-    pub network_id: u8,
+pub struct LedgerTransactionOutputWithExtraInfo<'a> {
+    pub transaction_output: &'a LedgerTransactionOutput,
+    pub network: &'a Network,
     pub data_cost: &'a csl::DataCost,
 }
 
-impl TryFromPLA<TransactionOutputWithExtraInfo<'_>> for csl::TransactionOutput {
-    fn try_from_pla(val: &TransactionOutputWithExtraInfo<'_>) -> Result<Self, TryFromPLAError> {
-        let mut output_builder = csl::TransactionOutputBuilder::new().with_address(
-            &AddressWithExtraInfo {
-                address: &val.transaction_output.address,
-                network_tag: val.network_id,
-            }
-            .try_to_csl()?,
-        );
+impl<'a> LedgerTransactionOutputWithExtraInfo<'a> {
+    pub fn new(
+        transaction_output: &'a LedgerTransactionOutput,
+        network: &'a Network,
+        data_cost: &'a csl::DataCost,
+    ) -> Self {
+        LedgerTransactionOutputWithExtraInfo {
+            transaction_output,
+            network,
+            data_cost,
+        }
+    }
+}
 
-        output_builder = match &val.transaction_output.datum {
-            OutputDatum::None => output_builder,
-            OutputDatum::InlineDatum(Datum(d)) => output_builder.with_plutus_data(&d.try_to_csl()?),
-            OutputDatum::DatumHash(dh) => output_builder.with_data_hash(&dh.try_to_csl()?),
-        };
+impl TryFromPLA<LedgerTransactionOutputWithExtraInfo<'_>> for csl::TransactionOutput {
+    fn try_from_pla(
+        val: &LedgerTransactionOutputWithExtraInfo<'_>,
+    ) -> Result<Self, TryFromPLAError> {
+        let output = val.transaction_output;
 
-        let script_ref = val
-            .transaction_output
+        csl_transaction_output(
+            output.address.with_extra_info(val.network).try_to_csl()?,
+            &output.value,
+            &output.datum,
+            output.reference_script.as_ref(),
+            val.data_cost,
+        )
+    }
+}
+
+/// Build a CSL transaction output, adding the minimum Ada to its value if needed
+fn csl_transaction_output(
+    address: csl::Address,
+    value: &Value,
+    datum: &OutputDatum,
+    reference_script: Option<&Script>,
+    data_cost: &csl::DataCost,
+) -> Result<csl::TransactionOutput, TryFromPLAError> {
+    let mut output_builder = csl::TransactionOutputBuilder::new().with_address(&address);
+
+    output_builder = match datum {
+        OutputDatum::None => output_builder,
+        OutputDatum::InlineDatum(Datum(d)) => output_builder.with_plutus_data(&d.try_to_csl()?),
+        OutputDatum::DatumHash(dh) => output_builder.with_data_hash(&dh.try_to_csl()?),
+    };
+
+    let script_ref = reference_script
+        .map(csl::ScriptRef::try_from_pla)
+        .transpose()?;
+
+    if let Some(script_ref) = &script_ref {
+        output_builder = output_builder.with_script_ref(script_ref);
+    };
+
+    let value_without_min_utxo = value.try_to_csl()?;
+
+    let mut calc =
+        csl::MinOutputAdaCalculator::new_empty(data_cost).map_err(TryFromPLAError::CSLJsError)?;
+    calc.set_amount(&value_without_min_utxo);
+    match datum {
+        OutputDatum::None => {}
+        OutputDatum::InlineDatum(Datum(d)) => {
+            calc.set_plutus_data(&d.try_to_csl()?);
+        }
+        OutputDatum::DatumHash(dh) => {
+            calc.set_data_hash(&dh.try_to_csl()?);
+        }
+    };
+    if let Some(script_ref) = script_ref {
+        calc.set_script_ref(&script_ref);
+    }
+
+    let required_coin = calc.calculate_ada().map_err(TryFromPLAError::CSLJsError)?;
+    let coin = std::cmp::max(value_without_min_utxo.coin(), required_coin);
+
+    let value = match value_without_min_utxo.multiasset() {
+        Some(multiasset) => csl::Value::new_with_assets(&coin, &multiasset),
+        None => csl::Value::new(&coin),
+    };
+
+    output_builder
+        .next()
+        .map_err(TryFromPLAError::CSLJsError)?
+        .with_value(&value)
+        .build()
+        .map_err(TryFromPLAError::CSLJsError)
+}
+
+//////////////////
+// Minimum UTxO //
+//////////////////
+
+/// Fixed overhead of a UTxO entry, in bytes, added to the size of the serialized output when
+/// computing its minimum Ada value (Babbage and later eras)
+const UTXO_ENTRY_OVERHEAD: usize = 160;
+
+#[derive(thiserror::Error, Debug)]
+pub enum MinAdaError {
+    #[error("Reference script {0:?} is missing from the script lookup")]
+    MissingScript(ScriptHash),
+}
+
+impl TransactionOutput {
+    /// Calculate the minimum amount of Lovelace required to be held by this output
+    /// (Babbage and Conway eras).
+    ///
+    /// The size of the output is calculated natively, the same way it would be serialized by CSL,
+    /// including its inline datum and reference script. As a larger Lovelace amount might take
+    /// more space, the Ada amount of the output is taken into account.
+    /// Reference scripts are looked up from `scripts` by their hash.
+    pub fn min_ada(
+        &self,
+        scripts: &BTreeMap<ScriptHash, Script>,
+        coins_per_utxo_byte: &BigInt,
+    ) -> Result<BigInt, MinAdaError> {
+        let script_size = self
             .reference_script
-            .clone()
-            .map(|script_hash| -> Result<_, TryFromPLAError> {
-                let script = val
-                    .scripts
-                    .get(&script_hash)
-                    .ok_or(TryFromPLAError::MissingScript(script_hash))?;
-                Ok(csl::ScriptRef::new_plutus_script(script))
+            .as_ref()
+            .map(|script_hash| {
+                scripts
+                    .get(script_hash)
+                    .map(script_size)
+                    .ok_or_else(|| MinAdaError::MissingScript(script_hash.clone()))
             })
             .transpose()?;
 
-        if let Some(script_ref) = &script_ref {
-            output_builder = output_builder.with_script_ref(script_ref);
-        };
+        let mut coin = self.value.get_ada_amount();
 
-        let value_without_min_utxo = val.transaction_output.value.try_to_csl()?;
+        loop {
+            let size = self.serialized_size(&coin, script_size);
+            let required_coin = BigInt::from(size + UTXO_ENTRY_OVERHEAD) * coins_per_utxo_byte;
 
-        let mut calc = csl::MinOutputAdaCalculator::new_empty(val.data_cost)
-            .map_err(TryFromPLAError::CSLJsError)?;
-        calc.set_amount(&value_without_min_utxo);
-        match &val.transaction_output.datum {
-            OutputDatum::None => {}
-            OutputDatum::InlineDatum(Datum(d)) => {
-                calc.set_plutus_data(&d.try_to_csl()?);
-            }
-            OutputDatum::DatumHash(dh) => {
-                calc.set_data_hash(&dh.try_to_csl()?);
+            if coin >= required_coin {
+                return Ok(required_coin);
             }
-        };
-        if let Some(script_ref) = script_ref {
-            calc.set_script_ref(&script_ref);
+
+            coin = required_coin;
         }
+    }
 
-        let required_coin = calc.calculate_ada().map_err(TryFromPLAError::CSLJsError)?;
-        let coin = std::cmp::max(value_without_min_utxo.coin(), required_coin);
+    /// Top up the Ada amount of the output to the minimum required by the ledger.
+    /// Outputs already holding enough Ada are returned unchanged.
+    pub fn with_min_ada(
+        self,
+        scripts: &BTreeMap<ScriptHash, Script>,
+        coins_per_utxo_byte: &BigInt,
+    ) -> Result<Self, MinAdaError> {
+        let min_ada = self.min_ada(scripts, coins_per_utxo_byte)?;
+
+        if self.value.get_ada_amount() >= min_ada {
+            Ok(self)
+        } else {
+            Ok(TransactionOutput {
+                value: self
+                    .value
+                    .insert_token(&CurrencySymbol::Ada, &TokenName::ada(), &min_ada),
+                ..self
+            })
+        }
+    }
 
-        let value = match value_without_min_utxo.multiasset() {
-            Some(multiasset) => csl::Value::new_with_assets(&coin, &multiasset),
-            None => csl::Value::new(&coin),
-        };
+    /// Size of the CBOR serialized output, using `coin` as its Lovelace amount
+    fn serialized_size(&self, coin: &BigInt, script_size: Option<usize>) -> usize {
+        let address_size = bytes_size(address_bytes_len(&self.address));
+        let value_size = self.value.serialized_size_with_coin(coin);
 
-        output_builder
-            .next()
-            .map_err(TryFromPLAError::CSLJsError)?
-            .with_value(&value)
-            .build()
-            .map_err(TryFromPLAError::CSLJsError)
+        // The script is serialized as a byte string wrapped in a CBOR tag 24
+        let script_ref_size = script_size.map(|size| 2 + bytes_size(size));
+
+        match (&self.datum, script_ref_size) {
+            // Legacy (pre-Babbage) output format: `[ address, value, ? datum_hash ]`
+            (OutputDatum::None, None) => 1 + address_size + value_size,
+            (OutputDatum::DatumHash(DatumHash(LedgerBytes(hash))), None) => {
+                1 + address_size + value_size + bytes_size(hash.len())
+            }
+            // Post-Alonzo output format: `{ 0: address, 1: value, ? 2: datum_option, ? 3: script_ref }`
+            (datum, script_ref_size) => {
+                let datum_option_size = match datum {
+                    OutputDatum::None => 0,
+                    OutputDatum::DatumHash(DatumHash(LedgerBytes(hash))) => {
+                        1 + 1 + 1 + bytes_size(hash.len())
+                    }
+                    OutputDatum::InlineDatum(Datum(data)) => {
+                        1 + 1 + 1 + 2 + bytes_size(plutus_data_size(data))
+                    }
+                };
+
+                1 + 1
+                    + address_size
+                    + 1
+                    + value_size
+                    + datum_option_size
+                    + script_ref_size.map_or(0, |size| 1 + size)
+            }
+        }
     }
 }
 
+/// Size of the CBOR serialized `[ script_type, script ]` pair of a reference script.
+/// Native scripts are embedded as is, while Plutus scripts are wrapped in a byte string.
+fn script_size(script: &Script) -> usize {
+    match script {
+        Script::Native(script) => 2 + script.to_cbor().len(),
+        Script::Plutus(PlutusScript {
+            bytes: LedgerBytes(bytes),
+            ..
+        }) => 2 + bytes_size(bytes.len()),
+    }
+}
+
+/// Length of the raw bytes of a Shelley address
+fn address_bytes_len(address: &Address) -> usize {
+    let credential_len = |credential: &Credential| match credential {
+        Credential::PubKey(Ed25519PubKeyHash(LedgerBytes(bytes)))
+        | Credential::Script(ValidatorHash(ScriptHash(LedgerBytes(bytes)))) => bytes.len(),
+    };
+
+    // Pointer fields are encoded as variable length natural numbers, 7 bits per byte
+    let var_nat_len = |n: &BigInt| std::cmp::max(1, n.bits().div_ceil(7) as usize);
+
+    let staking_len = match &address.staking_credential {
+        None => 0,
+        Some(StakingCredential::Hash(credential)) => credential_len(credential),
+        Some(StakingCredential::Pointer(ChainPointer {
+            slot_number,
+            transaction_index,
+            certificate_index,
+        })) => {
+            var_nat_len(&slot_number.0)
+                + var_nat_len(&transaction_index.0)
+                + var_nat_len(&certificate_index.0)
+        }
+    };
+
+    1 + credential_len(&address.credential) + staking_len
+}
+
 //////////////
 // TxInInfo //
 //////////////
@@ -180,6 +467,20 @@ impl From<(TransactionInput, TransactionOutput)> for TxInInfo {
     }
 }
 
+/// Build a `TxInInfo` from an entry of the UTxO set, failing for Byron addresses
+impl TryFrom<(TransactionInput, LedgerTransactionOutput)> for TxInInfo {
+    type Error = AddressError;
+
+    fn try_from(
+        (reference, output): (TransactionInput, LedgerTransactionOutput),
+    ) -> Result<TxInInfo, Self::Error> {
+        Ok(TxInInfo {
+            reference,
+            output: output.try_into()?,
+        })
+    }
+}
+
 // TransactionInfo //
 /////////////////////
 
@@ -203,10 +504,58 @@ pub struct TransactionInfo {
     pub id: TransactionHash,
 }
 
+impl TransactionInfo {
+    /// Reward withdrawals of the transaction, with the reward addresses on the network
+    ///
+    /// Withdrawals of chain pointers, which have no reward address, are skipped.
+    pub fn withdrawals(&self, network: &Network) -> Vec<(RewardAddress, BigInt)> {
+        self.wdrl
+            .0
+            .iter()
+            .filter_map(|(staking_credential, amount)| match staking_credential {
+                StakingCredential::Hash(credential) => Some((
+                    RewardAddress {
+                        network_tag: network.network_id,
+                        credential: credential.clone(),
+                    },
+                    amount.clone(),
+                )),
+                StakingCredential::Pointer(_) => None,
+            })
+            .collect()
+    }
+
+    /// Amount withdrawn from the reward address (the network of the address is ignored)
+    pub fn withdrawal(&self, reward_address: &RewardAddress) -> Option<&BigInt> {
+        self.wdrl
+            .0
+            .iter()
+            .find(|(staking_credential, _)| match staking_credential {
+                StakingCredential::Hash(credential) => *credential == reward_address.credential,
+                StakingCredential::Pointer(_) => false,
+            })
+            .map(|(_, amount)| amount)
+    }
+
+    /// Verify that the witnesses sign the transaction id, and that all signatories signed it
+    pub fn verify_witnesses(&self, witnesses: &[VKeyWitness]) -> Result<(), WitnessError> {
+        verify_witnesses(&self.id, &self.signatories, witnesses)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct WithdrawalsWithExtraInfo<'a> {
     pub withdrawals: &'a AssocMap<StakingCredential, BigInt>,
-    pub network_tag: u8,
+    pub network: &'a Network,
+}
+
+impl<'a> WithdrawalsWithExtraInfo<'a> {
+    pub fn new(withdrawals: &'a AssocMap<StakingCredential, BigInt>, network: &'a Network) -> Self {
+        WithdrawalsWithExtraInfo {
+            withdrawals,
+            network,
+        }
+    }
 }
 
 impl TryFromPLA<WithdrawalsWithExtraInfo<'_>> for csl::Withdrawals {
@@ -215,14 +564,7 @@ impl TryFromPLA<WithdrawalsWithExtraInfo<'_>> for csl::Withdrawals {
             .0
             .iter()
             .try_fold(csl::Withdrawals::new(), |mut acc, (s, q)| {
-                acc.insert(
-                    &RewardAddressWithExtraInfo {
-                        staking_credential: s,
-                        network_tag: val.network_tag,
-                    }
-                    .try_to_csl()?,
-                    &q.try_to_csl()?,
-                );
+                acc.insert(&s.with_network(val.network).try_to_csl()?, &q.try_to_csl()?);
                 Ok(acc)
             })
     }
@@ -7,6 +7,7 @@ pub mod transaction;
 // Inherited from v2
 pub use crate::v2::address;
 pub use crate::v2::assoc_map;
+pub use crate::v2::coin_selection;
 pub use crate::v2::crypto;
 pub use crate::v2::datum;
 pub use crate::v2::interval;
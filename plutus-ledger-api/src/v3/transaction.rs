@@ -13,32 +13,35 @@ use nom::{
     sequence::{preceded, tuple},
     Finish, IResult,
 };
-use num_bigint::BigInt;
+use num_bigint::{BigInt, BigUint};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "chrono")]
 pub use crate::v1::transaction::POSIXTimeConversionError;
 pub use crate::v2::transaction::{
-    DCert, POSIXTime, POSIXTimeRange, TransactionOutput, TransactionOutputWithExtraInfo,
+    parse_cli_tx_out, DCert, LedgerTransactionOutput, LedgerTransactionOutputWithExtraInfo,
+    POSIXTime, POSIXTimeRange, TransactionOutput, TransactionOutputWithExtraInfo,
     WithdrawalsWithExtraInfo,
 };
 use crate::{
     self as plutus_ledger_api,
-    aux::{big_int, guard_bytes},
+    aux::{big_int, decode_bech32, encode_bech32, guard_bytes},
     csl::{
         csl_to_pla::FromCSL,
         pla_to_csl::{TryFromPLA, TryFromPLAError, TryToCSL},
     },
     error::ConversionError,
+    keys::witness::{verify_witnesses, VKeyWitness, WitnessError},
+    network::Network,
     plutus_data::{IsPlutusData, PlutusData},
     v2::{
-        address::Credential,
+        address::{AddressError, Credential, RewardAddress},
         assoc_map::AssocMap,
-        crypto::{PaymentPubKeyHash, StakePubKeyHash},
+        crypto::{PaymentPubKeyHash, PoolId, VrfKeyHash},
         datum::{Datum, DatumHash},
         redeemer::Redeemer,
-        script::ScriptHash,
+        script::{ScriptHash, ValidatorHash},
         value::{CurrencySymbol, Lovelace, Value},
     },
 };
@@ -79,6 +82,18 @@ impl TransactionHash {
     }
 }
 
+impl From<crate::v1::transaction::TransactionHash> for TransactionHash {
+    fn from(value: crate::v1::transaction::TransactionHash) -> Self {
+        TransactionHash(value.0)
+    }
+}
+
+impl From<TransactionHash> for crate::v1::transaction::TransactionHash {
+    fn from(value: TransactionHash) -> Self {
+        crate::v1::transaction::TransactionHash(value.0)
+    }
+}
+
 impl FromCSL<csl::TransactionHash> for TransactionHash {
     fn from_csl(value: &csl::TransactionHash) -> Self {
         TransactionHash(LedgerBytes(value.to_bytes()))
@@ -223,6 +238,26 @@ impl FromStr for TransactionInput {
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct ColdCommitteeCredential(pub Credential);
 
+/// Serialize into bech32 with the CIP-129 `cc_cold` prefix
+impl fmt::Display for ColdCommitteeCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_cip129_credential("cc_cold", CIP129_CC_COLD, &self.0).map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for ColdCommitteeCredential {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_cip129_credential("ColdCommitteeCredential", "cc_cold", CIP129_CC_COLD, s)
+            .map(ColdCommitteeCredential)
+    }
+}
+
 ////////////////////////////
 // HotCommitteeCredential //
 ////////////////////////////
@@ -233,6 +268,26 @@ pub struct ColdCommitteeCredential(pub Credential);
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct HotCommitteeCredential(pub Credential);
 
+/// Serialize into bech32 with the CIP-129 `cc_hot` prefix
+impl fmt::Display for HotCommitteeCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_cip129_credential("cc_hot", CIP129_CC_HOT, &self.0).map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for HotCommitteeCredential {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_cip129_credential("HotCommitteeCredential", "cc_hot", CIP129_CC_HOT, s)
+            .map(HotCommitteeCredential)
+    }
+}
+
 ////////////////////
 // DrepCredential //
 ////////////////////
@@ -243,6 +298,25 @@ pub struct HotCommitteeCredential(pub Credential);
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub struct DRepCredential(pub Credential);
 
+/// Serialize into bech32 with the CIP-129 `drep` prefix
+impl fmt::Display for DRepCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            encode_cip129_credential("drep", CIP129_DREP, &self.0).map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for DRepCredential {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_cip129_credential("DRepCredential", "drep", CIP129_DREP, s).map(DRepCredential)
+    }
+}
+
 //////////
 // DRep //
 //////////
@@ -266,9 +340,9 @@ pub enum DRep {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "lbf", derive(Json))]
 pub enum Delegatee {
-    Stake(StakePubKeyHash),
+    Stake(PoolId),
     Vote(DRep),
-    StakeVote(StakePubKeyHash, DRep),
+    StakeVote(PoolId, DRep),
 }
 
 ////////////
@@ -297,12 +371,12 @@ pub enum TxCert {
     /// A digest of the PoolParams
     PoolRegister(
         /// pool id
-        Ed25519PubKeyHash,
-        // pool vrf
-        Ed25519PubKeyHash,
+        PoolId,
+        /// pool vrf
+        VrfKeyHash,
     ),
     /// The retirement certificate and the Epoch in which the retirement will take place
-    PoolRetire(Ed25519PubKeyHash, BigInt),
+    PoolRetire(PoolId, BigInt),
     /// Authorize a Hot credential for a specific Committee member's cold credential
     AuthHotCommittee(ColdCommitteeCredential, HotCommitteeCredential),
     ResignColdCommittee(ColdCommitteeCredential),
@@ -319,7 +393,7 @@ pub enum TxCert {
 pub enum Voter {
     CommitteeVoter(HotCommitteeCredential),
     DRepVoter(DRepCredential),
-    StakePoolVoter(Ed25519PubKeyHash),
+    StakePoolVoter(PoolId),
 }
 
 ///////////
@@ -350,6 +424,73 @@ pub struct GovernanceActionId {
     pub gov_action_id: BigInt,
 }
 
+impl GovernanceActionId {
+    pub fn new(tx_id: TransactionHash, index: u16) -> Self {
+        GovernanceActionId {
+            tx_id,
+            gov_action_id: BigInt::from(index),
+        }
+    }
+
+    /// Encode in bech32 with the CIP-129 `gov_action` prefix: the transaction id, followed by the
+    /// index in big-endian (a single byte for indices below 256)
+    ///
+    /// Fails if the index is outside of the range of the ledger's 16-bit indices.
+    pub fn to_bech32(&self) -> Result<String, ConversionError> {
+        let index = u16::try_from(&self.gov_action_id).map_err(|_| {
+            ConversionError::ParseError(anyhow!(
+                "GovernanceActionId index {} is not in the range 0..={}",
+                self.gov_action_id,
+                u16::MAX
+            ))
+        })?;
+        let bytes = [
+            self.tx_id.0 .0.as_slice(),
+            &BigUint::from(index).to_bytes_be(),
+        ]
+        .concat();
+
+        encode_bech32("gov_action", &bytes).map_err(|err| ConversionError::ParseError(anyhow!(err)))
+    }
+}
+
+/// Serialize into bech32 (see `GovernanceActionId::to_bech32`)
+///
+/// Ids that cannot be encoded are displayed as `INVALID GOVERNANCE ACTION ID`, followed by the
+/// reason.
+impl fmt::Display for GovernanceActionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_bech32() {
+            Ok(bech32) => write!(f, "{}", bech32),
+            Err(err) => write!(f, "INVALID GOVERNANCE ACTION ID {:?}: {}", self, err),
+        }
+    }
+}
+
+impl FromStr for GovernanceActionId {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_bech32("GovernanceActionId", "gov_action", s)?;
+
+        if bytes.len() <= 32 || bytes.len() > 34 {
+            return Err(ConversionError::invalid_bytestring_length(
+                "GovernanceActionId",
+                34,
+                "between 33 and",
+                &bytes,
+            ));
+        }
+
+        let (tx_id, index) = bytes.split_at(32);
+
+        Ok(GovernanceActionId {
+            tx_id: TransactionHash::from_bytes(tx_id.to_vec())?,
+            gov_action_id: BigInt::from(BigUint::from_bytes_be(index)),
+        })
+    }
+}
+
 ///////////////
 // Committee //
 ///////////////
@@ -527,6 +668,39 @@ pub struct TransactionInfo {
     pub treasury_donation: Option<Lovelace>,
 }
 
+impl TransactionInfo {
+    /// Reward withdrawals of the transaction, with the reward addresses on the network
+    pub fn withdrawals(&self, network: &Network) -> Vec<(RewardAddress, Lovelace)> {
+        self.wdrl
+            .0
+            .iter()
+            .map(|(credential, amount)| {
+                (
+                    RewardAddress {
+                        network_tag: network.network_id,
+                        credential: credential.clone(),
+                    },
+                    amount.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Amount withdrawn from the reward address (the network of the address is ignored)
+    pub fn withdrawal(&self, reward_address: &RewardAddress) -> Option<&Lovelace> {
+        self.wdrl
+            .0
+            .iter()
+            .find(|(credential, _)| *credential == reward_address.credential)
+            .map(|(_, amount)| amount)
+    }
+
+    /// Verify that the witnesses sign the transaction id, and that all signatories signed it
+    pub fn verify_witnesses(&self, witnesses: &[VKeyWitness]) -> Result<(), WitnessError> {
+        verify_witnesses(&self.id.clone().into(), &self.signatories, witnesses)
+    }
+}
+
 //////////////
 // TxInInfo //
 //////////////
@@ -547,6 +721,20 @@ impl From<(TransactionInput, TransactionOutput)> for TxInInfo {
     }
 }
 
+/// Build a `TxInInfo` from an entry of the UTxO set, failing for Byron addresses
+impl TryFrom<(TransactionInput, LedgerTransactionOutput)> for TxInInfo {
+    type Error = AddressError;
+
+    fn try_from(
+        (reference, output): (TransactionInput, LedgerTransactionOutput),
+    ) -> Result<TxInInfo, Self::Error> {
+        Ok(TxInInfo {
+            reference,
+            output: output.try_into()?,
+        })
+    }
+}
+
 ///////////////////
 // ScriptContext //
 ///////////////////
@@ -560,3 +748,52 @@ pub struct ScriptContext {
     pub redeemer: Redeemer,
     pub script_info: ScriptInfo,
 }
+
+/////////////
+// CIP-129 //
+/////////////
+
+/// Key types of the CIP-129 header byte, in its high nibble
+const CIP129_CC_HOT: u8 = 0x0;
+const CIP129_CC_COLD: u8 = 0x1;
+const CIP129_DREP: u8 = 0x2;
+
+/// Encode a governance credential in bech32 following CIP-129: a header byte holding the key type
+/// and the credential type (`0x2` for key hashes, `0x3` for script hashes), followed by the hash
+fn encode_cip129_credential(
+    prefix: &str,
+    key_type: u8,
+    credential: &Credential,
+) -> Result<String, bech32::Error> {
+    let (credential_type, hash) = match credential {
+        Credential::PubKey(pkh) => (0x2, &pkh.0 .0),
+        Credential::Script(ValidatorHash(ScriptHash(hash))) => (0x3, &hash.0),
+    };
+
+    encode_bech32(
+        prefix,
+        &[&[key_type << 4 | credential_type], hash.as_slice()].concat(),
+    )
+}
+
+fn decode_cip129_credential(
+    ctx: &str,
+    prefix: &str,
+    key_type: u8,
+    s: &str,
+) -> Result<Credential, ConversionError> {
+    let bytes = guard_bytes(ctx, decode_bech32(ctx, prefix, s)?, 29)?;
+    let header = bytes[0];
+    let hash = LedgerBytes(bytes[1..].to_vec());
+
+    match (header >> 4, header & 0x0f) {
+        (kt, 0x2) if kt == key_type => Ok(Credential::PubKey(Ed25519PubKeyHash(hash))),
+        (kt, 0x3) if kt == key_type => Ok(Credential::Script(ValidatorHash(ScriptHash(hash)))),
+        _ => Err(ConversionError::ParseError(anyhow!(
+            "Error while parsing {} '{}': invalid CIP-129 header byte {:#04x}",
+            ctx,
+            s,
+            header
+        ))),
+    }
+}
@@ -0,0 +1,750 @@
+#[cfg(test)]
+mod cip19_tests {
+    use std::str::FromStr;
+
+    use cardano_serialization_lib as csl;
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        csl::{csl_to_pla::TryFromCSL, pla_to_csl::TryToCSL},
+        generators::correct::{
+            v1::{arb_address, arb_reward_address},
+            v3::arb_transaction_info as v3_arb_transaction_info,
+        },
+        goldens::{
+            v1::sample_credential, v2::sample_transaction_info as v2_sample_transaction_info,
+        },
+        network::Network,
+        v1::{
+            address::{
+                Address, AddressError, CertificateIndex, ChainPointer, Credential, RewardAddress,
+                RewardAddressWithExtraInfo, Slot, StakingCredential, TransactionIndex,
+            },
+            crypto::{Ed25519PubKeyHash, LedgerBytes},
+            script::{ScriptHash, ValidatorHash},
+        },
+    };
+    use proptest::prelude::*;
+
+    fn payment_key() -> Credential {
+        Credential::PubKey(Ed25519PubKeyHash(LedgerBytes(hex(
+            "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e",
+        ))))
+    }
+
+    fn stake_key() -> Credential {
+        Credential::PubKey(Ed25519PubKeyHash(LedgerBytes(hex(
+            "337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251",
+        ))))
+    }
+
+    fn script() -> Credential {
+        Credential::Script(ValidatorHash(ScriptHash(LedgerBytes(hex(
+            "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f",
+        )))))
+    }
+
+    fn pointer() -> StakingCredential {
+        StakingCredential::Pointer(ChainPointer {
+            slot_number: Slot(BigInt::from(2498243)),
+            transaction_index: TransactionIndex(BigInt::from(27)),
+            certificate_index: CertificateIndex(BigInt::from(3)),
+        })
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        data_encoding::HEXLOWER.decode(s.as_bytes()).unwrap()
+    }
+
+    fn address(credential: Credential, staking_credential: Option<StakingCredential>) -> Address {
+        Address {
+            credential,
+            staking_credential,
+        }
+    }
+
+    /// Test vectors of CIP-19
+    fn address_vectors() -> Vec<(&'static str, Address, u8)> {
+        vec![
+            (
+                "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x",
+                address(payment_key(), Some(StakingCredential::Hash(stake_key()))),
+                1,
+            ),
+            (
+                "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh",
+                address(script(), Some(StakingCredential::Hash(stake_key()))),
+                1,
+            ),
+            (
+                "addr1yx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerkr0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shs2z78ve",
+                address(payment_key(), Some(StakingCredential::Hash(script()))),
+                1,
+            ),
+            (
+                "addr1x8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gt7r0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shskhj42g",
+                address(script(), Some(StakingCredential::Hash(script()))),
+                1,
+            ),
+            (
+                "addr1gx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrzqf96k",
+                address(payment_key(), Some(pointer())),
+                1,
+            ),
+            (
+                "addr128phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtupnz75xxcrtw79hu",
+                address(script(), Some(pointer())),
+                1,
+            ),
+            (
+                "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8",
+                address(payment_key(), None),
+                1,
+            ),
+            (
+                "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx",
+                address(script(), None),
+                1,
+            ),
+            (
+                "addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs68faae",
+                address(payment_key(), Some(StakingCredential::Hash(stake_key()))),
+                0,
+            ),
+            (
+                "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz",
+                address(payment_key(), None),
+                0,
+            ),
+        ]
+    }
+
+    fn reward_address_vectors() -> Vec<(&'static str, Credential, u8)> {
+        vec![
+            (
+                "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw",
+                stake_key(),
+                1,
+            ),
+            (
+                "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5",
+                script(),
+                1,
+            ),
+            (
+                "stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn",
+                stake_key(),
+                0,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_decode_addresses() {
+        for (bech32, address, network_tag) in address_vectors() {
+            assert_eq!(
+                Address::from_bech32(bech32).unwrap(),
+                (address, network_tag)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_addresses() {
+        for (bech32, address, network_tag) in address_vectors() {
+            assert_eq!(
+                address
+                    .with_extra_info(&Network::from_network_id(network_tag))
+                    .to_bech32()
+                    .unwrap(),
+                bech32
+            );
+            assert_eq!(
+                address
+                    .with_extra_info(&Network::from_network_id(network_tag))
+                    .to_string(),
+                bech32
+            );
+        }
+    }
+
+    #[test]
+    fn test_reward_addresses() {
+        for (bech32, credential, network_tag) in reward_address_vectors() {
+            let reward_address = RewardAddress {
+                network_tag,
+                credential,
+            };
+
+            assert_eq!(RewardAddress::from_str(bech32).unwrap(), reward_address);
+            assert_eq!(reward_address.to_string(), bech32);
+        }
+    }
+
+    #[test]
+    fn test_address_reward_address() {
+        let (_, base_address, _) = &address_vectors()[0];
+        let (reward_address, ..) = &reward_address_vectors()[0];
+
+        assert_eq!(
+            base_address
+                .reward_address(&Network::mainnet())
+                .unwrap()
+                .to_string(),
+            *reward_address
+        );
+
+        let (_, pointer_address, _) = &address_vectors()[4];
+        assert_eq!(pointer_address.reward_address(&Network::mainnet()), None);
+    }
+
+    #[test]
+    fn test_withdrawals() {
+        let tx_info = v2_sample_transaction_info();
+        let reward_address = RewardAddress {
+            network_tag: 0,
+            credential: sample_credential(),
+        };
+
+        assert_eq!(
+            tx_info.withdrawals(&Network::preprod()),
+            vec![(reward_address.clone(), BigInt::from(12))]
+        );
+        assert_eq!(tx_info.withdrawal(&reward_address), Some(&BigInt::from(12)));
+        assert_eq!(
+            tx_info.withdrawal(&RewardAddress {
+                network_tag: 0,
+                credential: stake_key(),
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unexpected_address_types() {
+        let (reward_address, ..) = &reward_address_vectors()[0];
+        let (payment_address, ..) = &address_vectors()[0];
+
+        assert!(matches!(
+            Address::from_str(reward_address),
+            Err(AddressError::UnexpectedAddressType { got: 0b1110, .. })
+        ));
+        assert!(matches!(
+            RewardAddress::from_str(payment_address),
+            Err(AddressError::UnexpectedAddressType { got: 0b0000, .. })
+        ));
+        assert!(matches!(
+            Address::from_bytes(&[0b1000_0000]),
+            Err(AddressError::UnsupportedAddressType(0b1000))
+        ));
+        assert!(matches!(Address::from_bytes(&[]), Err(AddressError::Empty)));
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        let (_, base_address, _) = &address_vectors()[0];
+        let mut bytes = base_address
+            .with_extra_info(&Network::from_network_id(1))
+            .to_bytes()
+            .unwrap();
+        bytes.pop();
+
+        assert!(matches!(
+            Address::from_bytes(&bytes),
+            Err(AddressError::InvalidLength {
+                address_type: 0,
+                expected: 57,
+                got: 56
+            })
+        ));
+
+        let (_, pointer_address, _) = &address_vectors()[4];
+        let mut bytes = pointer_address
+            .with_extra_info(&Network::from_network_id(1))
+            .to_bytes()
+            .unwrap();
+        *bytes.last_mut().unwrap() |= 0x80;
+
+        assert!(matches!(
+            Address::from_bytes(&bytes),
+            Err(AddressError::TruncatedVariableLengthInteger)
+        ));
+
+        assert!(matches!(
+            base_address
+                .with_extra_info(&Network::from_network_id(16))
+                .to_bytes(),
+            Err(AddressError::InvalidNetworkTag(16))
+        ));
+        assert!(matches!(
+            address(
+                Credential::PubKey(Ed25519PubKeyHash(LedgerBytes(vec![0; 27]))),
+                None
+            )
+            .with_extra_info(&Network::from_network_id(1))
+            .to_bytes(),
+            Err(AddressError::InvalidCredentialLength(27))
+        ));
+        assert!(matches!(
+            RewardAddressWithExtraInfo {
+                staking_credential: &pointer(),
+                network: &Network::mainnet(),
+            }
+            .to_bytes(),
+            Err(AddressError::PointerRewardAddress)
+        ));
+    }
+
+    #[test]
+    fn test_network_prefix_mismatch() {
+        let (_, address, _) = &address_vectors()[0];
+        let bytes = address
+            .with_extra_info(&Network::from_network_id(0))
+            .to_bytes()
+            .unwrap();
+        let mainnet_prefixed = bech32::encode("addr", bech32::ToBase32::to_base32(&bytes)).unwrap();
+
+        assert!(matches!(
+            Address::from_bech32(&mainnet_prefixed),
+            Err(AddressError::InvalidBech32Prefix { .. })
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_address_roundtrip(val in arb_address(), network_tag in 0u8..16) {
+            let bytes = val.with_extra_info(&Network::from_network_id(network_tag)).to_bytes()?;
+
+            prop_assert_eq!(Address::from_bytes(&bytes)?, (val.clone(), network_tag));
+            prop_assert_eq!(
+                Address::from_bech32(&val.with_extra_info(&Network::from_network_id(network_tag)).to_bech32()?)?,
+                (val, network_tag)
+            );
+        }
+
+        #[test]
+        fn test_address_matches_csl(val in arb_address(), network_tag in 0u8..16) {
+            let csl_address: csl::Address = val.with_extra_info(&Network::from_network_id(network_tag)).try_to_csl()?;
+
+            prop_assert_eq!(val.with_extra_info(&Network::from_network_id(network_tag)).to_bytes()?, csl_address.to_bytes());
+        }
+
+        #[test]
+        fn test_reward_address_roundtrip(val in arb_reward_address()) {
+            prop_assert_eq!(RewardAddress::from_bytes(&val.to_bytes()?)?, val.clone());
+            prop_assert_eq!(RewardAddress::from_str(&val.to_string())?, val);
+        }
+
+        #[test]
+        fn test_v3_withdrawals(tx_info in v3_arb_transaction_info(), network_tag in 0u8..16) {
+            let network = Network::from_network_id(network_tag);
+
+            for (reward_address, amount) in tx_info.withdrawals(&network) {
+                prop_assert_eq!(reward_address.network_tag, network_tag);
+                prop_assert!(tx_info.withdrawal(&reward_address).is_some());
+                prop_assert!(tx_info.wdrl.0.iter().any(|(_, wdrl_amount)| *wdrl_amount == amount));
+            }
+        }
+
+        #[test]
+        fn test_reward_address_csl_roundtrip(val in arb_reward_address()) {
+            let csl_reward_address: csl::RewardAddress = val.try_to_csl()?;
+
+            prop_assert_eq!(val.to_bytes()?, csl_reward_address.to_address().to_bytes());
+            prop_assert_eq!(RewardAddress::try_from_csl(&csl_reward_address)?, val.clone());
+            prop_assert_eq!(
+                RewardAddress::try_from_csl(&csl_reward_address.to_address())?,
+                val
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod byron_tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use cardano_serialization_lib as csl;
+    use plutus_ledger_api::{
+        byron::{ByronAddress, ByronAddressAttributes, ByronAddressError, ByronAddressType},
+        csl::{
+            csl_to_pla::{TryFromCSL, TryToPLA},
+            pla_to_csl::{TryFromPLAError, TryToCSL},
+        },
+        generators::correct::v1::{arb_byron_address, arb_ledger_address},
+        goldens::{v1::sample_transaction_input, v2::sample_transaction_output},
+        native_script::NativeScript,
+        network::Network,
+        v1::{
+            address::{Address, AddressError, LedgerAddress},
+            crypto::LedgerBytes,
+            script::Script,
+        },
+        v2::transaction::{
+            LedgerTransactionOutput, LedgerTransactionOutputWithExtraInfo, TransactionOutput,
+            TxInInfo,
+        },
+    };
+    use proptest::prelude::*;
+
+    /// Icarus style mainnet address, without attributes
+    const MAINNET: &str = "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+
+    /// Daedalus style preprod address, with a derivation path and a network magic
+    const PREPROD: &str = "KjgoiXJS2coTnqpCLHXFtd89Hv9ttjsE6yW4msyLXFNkykUpTsyBs85r2rDDia2uKrhdpGKCJnmFXwvPSWLe75564ixZWdTxRh7TnuaDLnHx";
+
+    fn hex(s: &str) -> Vec<u8> {
+        data_encoding::HEXLOWER.decode(s.as_bytes()).unwrap()
+    }
+
+    fn byron_output(address: &str) -> LedgerTransactionOutput {
+        let output = sample_transaction_output();
+
+        LedgerTransactionOutput {
+            address: LedgerAddress::Byron(ByronAddress::from_str(address).unwrap()),
+            value: output.value,
+            datum: output.datum,
+            reference_script: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_byron_addresses() {
+        let mainnet = ByronAddress::from_base58(MAINNET).unwrap();
+
+        assert_eq!(
+            mainnet.root(),
+            &LedgerBytes(hex(
+                "4d947501de882f64dba476c342abc6b31979be1c8cfaa01f424b0779"
+            ))
+        );
+        assert_eq!(mainnet.attributes(), &ByronAddressAttributes::default());
+        assert_eq!(mainnet.address_type(), ByronAddressType::PubKey);
+        assert_eq!(mainnet.to_string(), MAINNET);
+
+        let preprod = ByronAddress::from_base58(PREPROD).unwrap();
+
+        assert_eq!(
+            preprod.attributes(),
+            &ByronAddressAttributes {
+                derivation_path: Some(LedgerBytes(hex(
+                    "fc15b0b8bb8d0e5f9f7a01c0477b47bd32e10aea16046fad41e56c2c"
+                ))),
+                network_magic: Some(1),
+            }
+        );
+        assert_eq!(preprod.to_string(), PREPROD);
+    }
+
+    #[test]
+    fn test_byron_addresses_match_csl() {
+        for s in [MAINNET, PREPROD] {
+            let csl_address = csl::ByronAddress::from_base58(s).unwrap();
+            let address = ByronAddress::try_from_csl(&csl_address).unwrap();
+
+            assert_eq!(address.to_bytes(), csl_address.to_bytes());
+            assert_eq!(
+                address.network_magic().unwrap_or(764824073),
+                csl_address.byron_protocol_magic()
+            );
+        }
+    }
+
+    #[test]
+    fn test_ledger_address_parsing() {
+        let (address, network_tag) =
+            LedgerAddress::from_bytes(&ByronAddress::from_str(PREPROD).unwrap().to_bytes())
+                .unwrap();
+
+        assert!(matches!(address, LedgerAddress::Byron(_)));
+        assert_eq!(network_tag, 0);
+        assert_eq!(
+            address
+                .with_extra_info(&Network::from_network_id(network_tag))
+                .to_string(),
+            PREPROD
+        );
+
+        let shelley = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+        assert_eq!(
+            LedgerAddress::from_str(shelley).unwrap(),
+            LedgerAddress::Shelley(Address::from_str(shelley).unwrap())
+        );
+        assert!(matches!(
+            LedgerAddress::from_str(MAINNET).unwrap(),
+            LedgerAddress::Byron(_)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_byron_addresses() {
+        let mut bytes = ByronAddress::from_str(MAINNET).unwrap().to_bytes();
+        *bytes.last_mut().unwrap() ^= 1;
+
+        assert!(matches!(
+            ByronAddress::from_bytes(&bytes),
+            Err(ByronAddressError::InvalidChecksum { .. })
+        ));
+
+        let mut bytes = ByronAddress::from_str(MAINNET).unwrap().to_bytes();
+        bytes.push(0);
+
+        assert!(matches!(
+            ByronAddress::from_bytes(&bytes),
+            Err(ByronAddressError::TrailingBytes)
+        ));
+        assert!(matches!(
+            ByronAddress::from_str("Ae2tdPwUPEZ0"),
+            Err(ByronAddressError::InvalidBase58Character('0'))
+        ));
+        assert!(matches!(
+            ByronAddress::new(
+                LedgerBytes(vec![0; 27]),
+                ByronAddressAttributes::default(),
+                ByronAddressType::PubKey
+            ),
+            Err(ByronAddressError::InvalidRootLength(27))
+        ));
+    }
+
+    #[test]
+    fn test_byron_address_in_script_context() {
+        let output = byron_output(MAINNET);
+
+        assert!(matches!(
+            TransactionOutput::try_from(output.clone()),
+            Err(AddressError::ByronAddress(_))
+        ));
+        assert!(matches!(
+            TxInInfo::try_from((sample_transaction_input(), output)),
+            Err(AddressError::ByronAddress(_))
+        ));
+
+        let script = Script::Native(NativeScript::After(0));
+        let shelley_output = TransactionOutput {
+            reference_script: Some(script.hash()),
+            ..sample_transaction_output()
+        };
+        let ledger_output = LedgerTransactionOutput::from_output(
+            shelley_output.clone(),
+            &BTreeMap::from([(script.hash(), script.clone())]),
+        )
+        .unwrap();
+
+        assert_eq!(ledger_output.reference_script, Some(script));
+        assert_eq!(
+            TxInInfo::try_from((sample_transaction_input(), ledger_output))
+                .unwrap()
+                .output,
+            shelley_output
+        );
+        assert!(matches!(
+            LedgerTransactionOutput::from_output(sample_transaction_output(), &BTreeMap::new()),
+            Err(TryFromPLAError::MissingScript(_))
+        ));
+    }
+
+    #[test]
+    fn test_byron_output_csl_roundtrip() {
+        let output = byron_output(PREPROD);
+        let csl_output: csl::TransactionOutput = LedgerTransactionOutputWithExtraInfo {
+            transaction_output: &output,
+            network: &Network::preprod(),
+            data_cost: &csl::DataCost::new_coins_per_byte(&csl::BigNum::from(4310u64)),
+        }
+        .try_to_csl()
+        .unwrap();
+
+        let roundtrip: LedgerTransactionOutput = csl_output.try_to_pla().unwrap();
+        assert_eq!(roundtrip.address, output.address);
+
+        assert!(TransactionOutput::try_from_csl(&csl_output).is_err());
+        assert!(Address::try_from_csl(&csl_output.address()).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_byron_address_roundtrip(val in arb_byron_address()) {
+            prop_assert_eq!(ByronAddress::from_bytes(&val.to_bytes())?, val.clone());
+            prop_assert_eq!(ByronAddress::from_str(&val.to_string())?, val);
+        }
+
+        #[test]
+        fn test_byron_address_csl_roundtrip(val in arb_byron_address()) {
+            let csl_address: csl::ByronAddress = val.try_to_csl()?;
+
+            prop_assert_eq!(
+                val.network_magic().unwrap_or(764824073),
+                csl_address.byron_protocol_magic()
+            );
+            prop_assert_eq!(ByronAddress::try_from_csl(&csl_address)?, val);
+        }
+
+        #[test]
+        fn test_ledger_address_csl_roundtrip(val in arb_ledger_address(), network_tag in 0u8..16) {
+            let csl_address: csl::Address = val.with_extra_info(&Network::from_network_id(network_tag)).try_to_csl()?;
+
+            prop_assert_eq!(LedgerAddress::try_from_csl(&csl_address)?, val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod network_tests {
+    use cardano_serialization_lib as csl;
+    use plutus_ledger_api::{
+        csl::pla_to_csl::TryToCSL,
+        generators::correct::v1::{arb_address, arb_credential},
+        network::Network,
+        slot_config::SlotConfig,
+        v1::address::{Address, AddressError, LedgerAddress, RewardAddress, StakingCredential},
+    };
+    use proptest::prelude::*;
+
+    const MAINNET_ADDRESS: &str = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+    const TESTNET_ADDRESS: &str = "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz";
+    const MAINNET_REWARD_ADDRESS: &str =
+        "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+    const MAINNET_BYRON_ADDRESS: &str =
+        "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+    const PREPROD_BYRON_ADDRESS: &str = "KjgoiXJS2coTnqpCLHXFtd89Hv9ttjsE6yW4msyLXFNkykUpTsyBs85r2rDDia2uKrhdpGKCJnmFXwvPSWLe75564ixZWdTxRh7TnuaDLnHx";
+
+    fn networks() -> Vec<Network> {
+        vec![Network::mainnet(), Network::preprod(), Network::preview()]
+    }
+
+    #[test]
+    fn test_presets() {
+        let mainnet = Network::mainnet();
+        assert!(mainnet.is_mainnet());
+        assert_eq!(mainnet.address_prefix, "addr");
+        assert_eq!(mainnet.reward_address_prefix, "stake");
+
+        let preprod = Network::preprod();
+        assert!(!preprod.is_mainnet());
+        assert_eq!(preprod.address_prefix, "addr_test");
+        assert_eq!(preprod.reward_address_prefix, "stake_test");
+        assert_eq!(
+            preprod.protocol_magic,
+            csl::NetworkInfo::testnet_preprod().protocol_magic()
+        );
+        assert_eq!(
+            Network::preview().protocol_magic,
+            csl::NetworkInfo::testnet_preview().protocol_magic()
+        );
+        assert_eq!(
+            mainnet.protocol_magic,
+            csl::NetworkInfo::mainnet().protocol_magic()
+        );
+    }
+
+    #[test]
+    fn test_address_network_validation() {
+        assert!(Address::from_bech32_with_network(MAINNET_ADDRESS, &Network::mainnet()).is_ok());
+        assert!(Address::from_bech32_with_network(TESTNET_ADDRESS, &Network::preview()).is_ok());
+        assert!(matches!(
+            Address::from_bech32_with_network(MAINNET_ADDRESS, &Network::preprod()),
+            Err(AddressError::NetworkMismatch {
+                expected: 0,
+                got: 1
+            })
+        ));
+        assert!(matches!(
+            RewardAddress::from_bech32_with_network(MAINNET_REWARD_ADDRESS, &Network::preview()),
+            Err(AddressError::NetworkMismatch {
+                expected: 0,
+                got: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_custom_network_prefixes() {
+        let devnet =
+            Network::new(0, 42, SlotConfig::preview()).with_prefixes("addr_dev", "stake_dev");
+        let (address, _) = Address::from_bech32(TESTNET_ADDRESS).unwrap();
+        let bech32 = address.with_extra_info(&devnet).to_bech32().unwrap();
+
+        assert!(bech32.starts_with("addr_dev1"));
+        assert_eq!(
+            Address::from_bech32_with_network(&bech32, &devnet).unwrap(),
+            address
+        );
+        assert!(matches!(
+            Address::from_bech32_with_network(TESTNET_ADDRESS, &devnet),
+            Err(AddressError::InvalidBech32Prefix { .. })
+        ));
+
+        let reward_address = RewardAddress {
+            network_tag: devnet.network_id,
+            credential: address.credential,
+        };
+        let bech32 = reward_address.to_bech32(&devnet).unwrap();
+
+        assert!(bech32.starts_with("stake_dev1"));
+        assert_eq!(
+            RewardAddress::from_bech32_with_network(&bech32, &devnet).unwrap(),
+            reward_address
+        );
+    }
+
+    #[test]
+    fn test_ledger_address_network_validation() {
+        assert!(matches!(
+            LedgerAddress::from_str_with_network(MAINNET_BYRON_ADDRESS, &Network::mainnet()),
+            Ok(LedgerAddress::Byron(_))
+        ));
+        assert!(matches!(
+            LedgerAddress::from_str_with_network(PREPROD_BYRON_ADDRESS, &Network::preprod()),
+            Ok(LedgerAddress::Byron(_))
+        ));
+        assert!(matches!(
+            LedgerAddress::from_str_with_network(PREPROD_BYRON_ADDRESS, &Network::preview()),
+            Err(AddressError::ProtocolMagicMismatch {
+                expected: 2,
+                got: 1
+            })
+        ));
+        assert!(matches!(
+            LedgerAddress::from_str_with_network(MAINNET_BYRON_ADDRESS, &Network::preprod()),
+            Err(AddressError::ProtocolMagicMismatch { expected: 1, .. })
+        ));
+        assert!(matches!(
+            LedgerAddress::from_str_with_network(TESTNET_ADDRESS, &Network::mainnet()),
+            Err(AddressError::NetworkMismatch { .. })
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_address_network_roundtrip(val in arb_address()) {
+            for network in networks() {
+                let bech32 = val.with_extra_info(&network).to_bech32()?;
+
+                prop_assert!(bech32.starts_with(&network.address_prefix));
+                prop_assert_eq!(Address::from_bech32_with_network(&bech32, &network)?, val.clone());
+
+                let csl_address: csl::Address = val.with_extra_info(&network).try_to_csl()?;
+                prop_assert_eq!(csl_address.network_id()?, network.network_id);
+            }
+        }
+
+        #[test]
+        fn test_reward_address_network_roundtrip(credential in arb_credential()) {
+            for network in networks() {
+                let reward_address = RewardAddress {
+                    network_tag: network.network_id,
+                    credential: credential.clone(),
+                };
+                let bech32 = reward_address.to_bech32(&network)?;
+
+                prop_assert!(bech32.starts_with(&network.reward_address_prefix));
+                prop_assert_eq!(&bech32, &StakingCredential::Hash(credential.clone()).with_network(&network).to_bech32()?);
+                prop_assert_eq!(
+                    RewardAddress::from_bech32_with_network(&bech32, &network)?,
+                    reward_address
+                );
+            }
+        }
+    }
+}
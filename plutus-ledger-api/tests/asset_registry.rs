@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod asset_registry_tests {
+    use std::str::FromStr;
+
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        asset_registry::{AssetMetadata, AssetRegistry},
+        generators::correct::{
+            primitive::arb_integer,
+            v1::{arb_currency_symbol, arb_token_name},
+        },
+        v1::value::{AssetClass, CurrencySymbol, TokenName, Value},
+    };
+    use proptest::prelude::*;
+
+    const POLICY: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373";
+
+    fn asset_class(token_name: &str) -> AssetClass {
+        AssetClass {
+            currency_symbol: CurrencySymbol::from_str(POLICY).unwrap(),
+            token_name: TokenName::from_string(token_name).unwrap(),
+        }
+    }
+
+    fn registry() -> AssetRegistry {
+        let mut registry = AssetRegistry::new();
+        registry.insert(
+            asset_class("HOSKY"),
+            AssetMetadata {
+                ticker: Some(String::from("HOSKY")),
+                name: None,
+                decimals: 6,
+            },
+        );
+        registry
+    }
+
+    fn sample_value() -> Value {
+        Value::ada_value(&BigInt::from(12_500_000))
+            + Value::token_value(
+                &asset_class("HOSKY").currency_symbol,
+                &asset_class("HOSKY").token_name,
+                &BigInt::from(3_000_001),
+            )
+            + Value::token_value(
+                &asset_class("MyNFT").currency_symbol,
+                &asset_class("MyNFT").token_name,
+                &BigInt::from(1),
+            )
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(
+            registry().format_value(&sample_value()),
+            format!("12.5 ADA + 3.000001 HOSKY + 1 {}.MyNFT", POLICY)
+        );
+        assert_eq!(registry().format_value(&Value::new()), "0 ADA");
+        assert_eq!(
+            registry().format_value(&Value::ada_value(&BigInt::from(-1_000_001))),
+            "-1.000001 ADA"
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(
+            registry()
+                .parse_value(&format!("12.5 ADA + 3.000001 HOSKY + 1 {}.MyNFT", POLICY))
+                .unwrap(),
+            sample_value()
+        );
+        assert_eq!(
+            registry().parse_value("5 lovelace").unwrap(),
+            Value::ada_value(&BigInt::from(5))
+        );
+        assert_eq!(
+            registry()
+                .parse_value(&format!("1 {}.0xff00", POLICY))
+                .unwrap(),
+            Value::token_value(
+                &CurrencySymbol::from_str(POLICY).unwrap(),
+                &TokenName::from_str("ff00").unwrap(),
+                &BigInt::from(1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_unusual_token_names() {
+        for token_name in ["A + B", "0xff", "two words"] {
+            assert_eq!(
+                registry().format_amount(&asset_class(token_name), &BigInt::from(1)),
+                format!(
+                    "1 {}.0x{}",
+                    POLICY,
+                    TokenName::from_string(token_name).unwrap()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_amounts() {
+        assert!(registry().parse_value("1.0000001 ADA").is_err());
+        assert!(registry().parse_value("1.5 lovelace").is_err());
+        assert!(registry().parse_value("1,5 ADA").is_err());
+        assert!(registry().parse_value("12.5").is_err());
+        assert!(registry().parse_value("1 UNKNOWN").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_cip26_dir() {
+        let mut registry = AssetRegistry::new();
+        let loaded = registry.load_cip26_dir("tests/testdata/cip26").unwrap();
+
+        assert_eq!(loaded, vec![asset_class("TOKEN")]);
+        assert_eq!(
+            registry.get(&asset_class("TOKEN")),
+            Some(&AssetMetadata {
+                ticker: Some(String::from("TOKEN")),
+                name: Some(String::from("Test Token")),
+                decimals: 6,
+            })
+        );
+        assert_eq!(
+            registry.format_amount(&asset_class("TOKEN"), &BigInt::from(1_230_000)),
+            "1.23 TOKEN"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_invalid_cip26_subject() {
+        let result = AssetRegistry::new().insert_cip26_json(r#"{ "subject": "aabb" }"#);
+
+        assert!(result.is_err());
+    }
+
+    proptest! {
+      #[test]
+      fn test_roundtrip(ada in arb_integer(), hosky in arb_integer(), nft in arb_integer(), raw in arb_integer()) {
+        let registry = registry();
+        let value = Value::ada_value(&ada)
+            + Value::token_value(&asset_class("HOSKY").currency_symbol, &asset_class("HOSKY").token_name, &hosky)
+            + Value::token_value(&asset_class("MyNFT").currency_symbol, &asset_class("MyNFT").token_name, &nft)
+            + Value::token_value(
+                &CurrencySymbol::from_str(POLICY).unwrap(),
+                &TokenName::from_str("ff00").unwrap(),
+                &raw,
+            );
+
+        prop_assert_eq!(registry.parse_value(&registry.format_value(&value))?, value);
+      }
+
+      #[test]
+      fn test_token_name_roundtrip(
+        currency_symbol in arb_currency_symbol(),
+        token_name in prop_oneof![
+            arb_token_name(),
+            "\\PC{0,8}".prop_map(|name| TokenName::from_string(&name).unwrap()),
+        ],
+        amount in arb_integer(),
+      ) {
+        let registry = AssetRegistry::new();
+        let asset_class = AssetClass {
+            token_name: if currency_symbol.is_ada() { TokenName::ada() } else { token_name },
+            currency_symbol,
+        };
+
+        prop_assert_eq!(
+            registry.parse_amount(&registry.format_amount(&asset_class, &amount))?,
+            (asset_class, amount)
+        );
+      }
+    }
+}
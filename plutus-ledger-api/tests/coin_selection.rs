@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod coin_selection_tests {
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        generators::correct::{v1::arb_transaction_input, v2::arb_transaction_output},
+        goldens::{v1::sample_transaction_input, v2::sample_transaction_output},
+        v2::{
+            coin_selection::{largest_first, random_improve, CoinSelection, CoinSelectionError},
+            transaction::{TransactionInput, TransactionOutput},
+            value::Value,
+        },
+    };
+    use proptest::{collection::btree_map, prelude::*};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Strategy to generate a non-empty UTxO set, and a target value covered by some of them
+    fn arb_utxos_with_target(
+    ) -> impl Strategy<Value = (Vec<(TransactionInput, TransactionOutput)>, Value)> {
+        btree_map(arb_transaction_input(), arb_transaction_output(), 1..20)
+            .prop_map(|utxos| utxos.into_iter().collect::<Vec<_>>())
+            .prop_flat_map(|utxos| {
+                let len = utxos.len();
+                (Just(utxos), 0..=len)
+            })
+            .prop_map(|(utxos, count)| {
+                let target = utxos[..count].iter().map(|(_, output)| &output.value).sum();
+                (utxos, target)
+            })
+    }
+
+    fn check_selection(
+        utxos: &[(TransactionInput, TransactionOutput)],
+        target: &Value,
+        selection: &CoinSelection,
+    ) -> Result<(), TestCaseError> {
+        let selected: Value = selection
+            .inputs
+            .iter()
+            .map(|(_, output)| &output.value)
+            .sum();
+
+        prop_assert!(selection.inputs.iter().all(|utxo| utxos.contains(utxo)));
+        prop_assert!(target.leq(&selected));
+        prop_assert_eq!(&selection.change, &(&selected - target).normalize());
+        Ok(())
+    }
+
+    fn ada_utxo(index: u32, amount: u64) -> (TransactionInput, TransactionOutput) {
+        (
+            TransactionInput {
+                index: BigInt::from(index),
+                ..sample_transaction_input()
+            },
+            TransactionOutput {
+                value: Value::ada_value(&BigInt::from(amount)),
+                ..sample_transaction_output()
+            },
+        )
+    }
+
+    #[test]
+    fn test_largest_first_picks_largest() {
+        let utxos = vec![ada_utxo(0, 1), ada_utxo(1, 5), ada_utxo(2, 3)];
+        let selection = largest_first(
+            &utxos,
+            &Value::ada_value(&BigInt::from(4)),
+            &BigInt::from(0),
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(selection.inputs, vec![utxos[1].clone()]);
+        assert_eq!(selection.change, Value::ada_value(&BigInt::from(1)));
+    }
+
+    #[test]
+    fn test_min_change_ada() {
+        let utxos = vec![ada_utxo(0, 1), ada_utxo(1, 5), ada_utxo(2, 3)];
+        let selection = largest_first(
+            &utxos,
+            &Value::ada_value(&BigInt::from(4)),
+            &BigInt::from(2),
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(selection.inputs, vec![utxos[1].clone(), utxos[2].clone()]);
+        assert_eq!(selection.change, Value::ada_value(&BigInt::from(4)));
+    }
+
+    #[test]
+    fn test_maximum_input_count() {
+        let utxos = vec![ada_utxo(0, 1), ada_utxo(1, 5), ada_utxo(2, 3)];
+        let result = largest_first(
+            &utxos,
+            &Value::ada_value(&BigInt::from(7)),
+            &BigInt::from(0),
+            1,
+        );
+
+        assert!(matches!(
+            result,
+            Err(CoinSelectionError::MaximumInputCountExceeded(1))
+        ));
+    }
+
+    proptest! {
+      #[test]
+      fn test_largest_first((utxos, target) in arb_utxos_with_target()) {
+        let selection = largest_first(&utxos, &target, &BigInt::from(0), utxos.len())?;
+        check_selection(&utxos, &target, &selection)?;
+      }
+
+      #[test]
+      fn test_random_improve((utxos, target) in arb_utxos_with_target(), seed in any::<u64>()) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let selection = random_improve(&utxos, &target, &BigInt::from(0), utxos.len(), &mut rng)?;
+        check_selection(&utxos, &target, &selection)?;
+      }
+
+      #[test]
+      fn test_random_improve_deterministic((utxos, target) in arb_utxos_with_target(), seed in any::<u64>()) {
+        let select = || random_improve(
+            &utxos,
+            &target,
+            &BigInt::from(0),
+            utxos.len(),
+            &mut StdRng::seed_from_u64(seed),
+        );
+
+        prop_assert_eq!(select()?, select()?);
+      }
+
+      #[test]
+      fn test_insufficient_balance((utxos, _) in arb_utxos_with_target()) {
+        let total: Value = utxos.iter().map(|(_, output)| &output.value).sum();
+        let target = total + Value::ada_value(&BigInt::from(1));
+
+        let result = largest_first(&utxos, &target, &BigInt::from(0), utxos.len());
+        prop_assert!(matches!(result, Err(CoinSelectionError::InsufficientBalance(_))));
+      }
+    }
+}
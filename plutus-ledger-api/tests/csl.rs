@@ -13,6 +13,7 @@ mod csl_pla_roundtrip_tests {
             arb_slot, arb_token_name, arb_transaction_hash, arb_transaction_index,
             arb_transaction_input, arb_value,
         },
+        network::Network,
         v1::{
             address::{Address, StakingCredential},
             value::Value,
@@ -124,9 +125,83 @@ mod csl_pla_roundtrip_tests {
       #[test]
       fn test_address(val in arb_address()) {
         prop_assert_eq!(
-            Address::try_from_csl(&val.with_extra_info(1).try_to_csl()?)?,
+            Address::try_from_csl(&val.with_extra_info(&Network::mainnet()).try_to_csl()?)?,
             val
         )
       }
     }
 }
+
+#[cfg(test)]
+mod csl_min_ada_tests {
+    use std::collections::BTreeMap;
+
+    use cardano_serialization_lib as csl;
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        csl::{csl_to_pla::FromCSL, pla_to_csl::TryToCSL},
+        generators::correct::{
+            v1::{arb_native_script, arb_plutus_script},
+            v2::arb_transaction_output,
+        },
+        network::Network,
+        v2::{
+            script::Script,
+            transaction::{TransactionOutput, TransactionOutputWithExtraInfo},
+            value::Value,
+        },
+    };
+    use proptest::{prop_assert, prop_assert_eq, prop_oneof, proptest, strategy::Strategy};
+
+    proptest! {
+      #[test]
+      fn test_min_ada(
+        output in arb_transaction_output(),
+        script in prop_oneof![
+            arb_native_script().prop_map(Script::Native),
+            arb_plutus_script().prop_map(Script::Plutus),
+        ],
+        coins_per_byte in 1..10_000u64
+      ) {
+        let script_hash = script.hash();
+        let scripts = BTreeMap::from([(script_hash.clone(), script)]);
+        let output = TransactionOutput {
+            reference_script: output.reference_script.map(|_| script_hash),
+            ..output
+        };
+
+        let data_cost = csl::DataCost::new_coins_per_byte(&csl::BigNum::from(coins_per_byte));
+        let csl_output: csl::TransactionOutput = TransactionOutputWithExtraInfo {
+            transaction_output: &output,
+            scripts: &scripts,
+            network: &Network::mainnet(),
+            data_cost: &data_cost,
+        }
+        .try_to_csl()?;
+
+        // CSL might have increased the Ada amount of the output
+        let output = TransactionOutput {
+            value: &output.value - &Value::ada_value(&output.value.get_ada_amount())
+                + Value::ada_value(&BigInt::from_csl(&csl_output.amount().coin())),
+            ..output
+        };
+
+        prop_assert_eq!(
+            output.min_ada(&scripts, &BigInt::from(coins_per_byte))?,
+            BigInt::from_csl(&csl::min_ada_for_output(&csl_output, &data_cost)?)
+        )
+      }
+
+      #[test]
+      fn test_with_min_ada(output in arb_transaction_output(), coins_per_byte in 1..10_000u64) {
+        let output = TransactionOutput { reference_script: None, ..output };
+        let coins_per_byte = BigInt::from(coins_per_byte);
+
+        let topped_up = output.clone().with_min_ada(&BTreeMap::new(), &coins_per_byte)?;
+        let min_ada = topped_up.min_ada(&BTreeMap::new(), &coins_per_byte)?;
+
+        prop_assert!(topped_up.value.get_ada_amount() >= min_ada);
+        prop_assert!(topped_up.value.get_ada_amount() >= output.value.get_ada_amount());
+      }
+    }
+}
@@ -3,9 +3,10 @@ mod display_serialisation_tests {
     mod golden_v1 {
         use plutus_ledger_api::{
             goldens::v1::{
-                sample_address, sample_asset_class, sample_currency_symbol,
+                sample_address, sample_asset_class, sample_currency_symbol, sample_plutus_interval,
                 sample_transaction_input, sample_value,
             },
+            network::Network,
             v1::value::CurrencySymbol,
             v3::value::TokenName,
         };
@@ -76,18 +77,35 @@ mod display_serialisation_tests {
 
         #[test]
         fn v1_address_display_1() {
-            goldie::assert!(format!("{}", sample_address().with_extra_info(0)))
+            goldie::assert!(format!(
+                "{}",
+                sample_address().with_extra_info(&Network::preprod())
+            ))
         }
 
         #[test]
         fn v1_address_display_2() {
-            goldie::assert!(format!("{}", sample_address().with_extra_info(1)))
+            goldie::assert!(format!(
+                "{}",
+                sample_address().with_extra_info(&Network::mainnet())
+            ))
         }
 
         #[test]
         fn v1_transaction_input_display() {
             goldie::assert!(format!("{}", sample_transaction_input()))
         }
+
+        #[test]
+        fn v1_plutus_interval_display() {
+            goldie::assert!(format!("{}", sample_plutus_interval()))
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn v1_plutus_interval_display_chrono() {
+            goldie::assert!(format!("{:#}", sample_plutus_interval()))
+        }
     }
 
     mod props_v1 {
@@ -95,9 +113,11 @@ mod display_serialisation_tests {
 
         use plutus_ledger_api::{
             generators::correct::v1::{
-                arb_address, arb_asset_class, arb_currency_symbol, arb_transaction_input, arb_value,
+                arb_address, arb_asset_class, arb_currency_symbol, arb_plutus_interval_posix_time,
+                arb_transaction_input, arb_value,
             },
-            v1::{address::Address, value::TokenName},
+            network::Network,
+            v1::{address::Address, interval::PlutusInterval, value::TokenName},
         };
         use proptest::{prelude::*, string::string_regex};
 
@@ -134,7 +154,7 @@ mod display_serialisation_tests {
 
             #[test]
             fn address(val in arb_address()) {
-                let roundtripped = Address::from_str(&val.with_extra_info(0).to_string()).unwrap();
+                let roundtripped = Address::from_str(&val.with_extra_info(&Network::preprod()).to_string()).unwrap();
 
                 assert_eq!(val, roundtripped);
             }
@@ -143,6 +163,362 @@ mod display_serialisation_tests {
             fn transaction_input(val in arb_transaction_input()) {
                 assert_eq!(val, from_to_string(&val)?);
             }
+
+            #[test]
+            fn plutus_interval(val in arb_plutus_interval_posix_time()) {
+                assert_eq!(val, from_to_string(&val)?);
+            }
+
+            #[cfg(feature = "chrono")]
+            #[test]
+            fn plutus_interval_chrono(val in arb_plutus_interval_posix_time()) {
+                let roundtripped = PlutusInterval::from_str(&format!("{:#}", val))?;
+
+                assert_eq!(val, roundtripped);
+            }
+        }
+    }
+
+    mod interval_notation {
+        use std::str::FromStr;
+
+        use num_bigint::BigInt;
+        use plutus_ledger_api::v1::{
+            interval::{
+                always, from, interval, to, Extended, LowerBound, PlutusInterval, UpperBound,
+            },
+            transaction::{POSIXTime, POSIXTimeRange},
+        };
+
+        fn time(millis: u64) -> POSIXTime {
+            POSIXTime(BigInt::from(millis))
+        }
+
+        #[test]
+        fn display_intervals() {
+            assert_eq!(from(time(1700000000000)).to_string(), "[1700000000000, +∞]");
+            assert_eq!(to(time(5)).to_string(), "[-∞, 5]");
+            assert_eq!(always::<POSIXTime>().to_string(), "[-∞, +∞]");
+            assert_eq!(interval(time(1), time(5)).to_string(), "[1, 5]");
+        }
+
+        #[test]
+        fn parse_intervals() {
+            assert_eq!(
+                POSIXTimeRange::from_str("(-∞, 1700000000000)").unwrap(),
+                PlutusInterval {
+                    from: LowerBound {
+                        bound: Extended::NegInf,
+                        closed: false,
+                    },
+                    to: UpperBound {
+                        bound: Extended::Finite(time(1700000000000)),
+                        closed: false,
+                    },
+                }
+            );
+            assert_eq!(
+                PlutusInterval::<BigInt>::from_str("[-5,-inf]").unwrap(),
+                PlutusInterval {
+                    from: LowerBound {
+                        bound: Extended::Finite(BigInt::from(-5)),
+                        closed: true,
+                    },
+                    to: UpperBound {
+                        bound: Extended::NegInf,
+                        closed: true,
+                    },
+                }
+            );
+            assert_eq!(POSIXTimeRange::from_str("[1, ∞]").unwrap(), from(time(1)));
+            assert_ne!(POSIXTimeRange::from_str("[1, ∞)").unwrap(), from(time(1)));
+            assert!(POSIXTimeRange::from_str("[1, 5").is_err());
+            assert!(POSIXTimeRange::from_str("[a, 5]").is_err());
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn parse_iso_8601_intervals() {
+            let range =
+                POSIXTimeRange::from_str("[2024-08-08T08:46:25Z, 2024-08-09T00:00:00Z)").unwrap();
+
+            assert_eq!(
+                range,
+                PlutusInterval {
+                    from: LowerBound {
+                        bound: Extended::Finite(time(1723106785000)),
+                        closed: true,
+                    },
+                    to: UpperBound {
+                        bound: Extended::Finite(time(1723161600000)),
+                        closed: false,
+                    },
+                }
+            );
+            assert_eq!(
+                format!("{:#}", range),
+                "[2024-08-08T08:46:25Z, 2024-08-09T00:00:00Z)"
+            );
+        }
+    }
+
+    mod bech32_ids {
+        use std::str::FromStr;
+
+        use num_bigint::BigInt;
+        use plutus_ledger_api::{
+            generators::correct::{
+                v1::{arb_ed25519_pub_key_hash, arb_pool_id, arb_script_hash, arb_vrf_key_hash},
+                v3::{
+                    arb_cold_committee_credential, arb_d_rep_credential,
+                    arb_hot_committee_credential, arb_transaction_hash,
+                },
+            },
+            v3::{
+                address::Credential,
+                crypto::{
+                    Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash, PoolId, StakePubKeyHash,
+                    VrfKeyHash,
+                },
+                script::{ScriptHash, ValidatorHash},
+                transaction::{
+                    ColdCommitteeCredential, DRepCredential, GovernanceActionId,
+                    HotCommitteeCredential, TransactionHash,
+                },
+            },
+        };
+        use proptest::prelude::*;
+
+        fn cip19_key_hash() -> Ed25519PubKeyHash {
+            Ed25519PubKeyHash::from_bytes(
+                data_encoding::HEXLOWER
+                    .decode(b"9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e")
+                    .unwrap(),
+            )
+            .unwrap()
+        }
+
+        fn cip19_script_hash() -> ScriptHash {
+            ScriptHash::from_bytes(
+                data_encoding::HEXLOWER
+                    .decode(b"c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f")
+                    .unwrap(),
+            )
+            .unwrap()
+        }
+
+        fn script_credential() -> Credential {
+            Credential::Script(ValidatorHash(cip19_script_hash()))
+        }
+
+        fn assert_bech32<T>(val: T, bech32: &str)
+        where
+            T: std::fmt::Display + FromStr + PartialEq + std::fmt::Debug,
+            T::Err: std::fmt::Debug,
+        {
+            assert_eq!(val.to_string(), bech32);
+            assert_eq!(T::from_str(bech32).unwrap(), val);
+        }
+
+        #[test]
+        fn cip5_key_hashes() {
+            assert_bech32(
+                cip19_key_hash(),
+                "addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg",
+            );
+            assert_bech32(
+                PaymentPubKeyHash(cip19_key_hash()),
+                "addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg",
+            );
+            assert_bech32(
+                StakePubKeyHash(cip19_key_hash()),
+                "stake_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkguekv27t",
+            );
+            assert_bech32(
+                cip19_script_hash(),
+                "script1cda3khwqv60360rp5m7akt50m6ttapacs8rqhn5w342z7r35m37",
+            );
+            assert_eq!(
+                cip19_key_hash().to_bech32("pool").unwrap(),
+                "pool1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkguxe990q"
+            );
+            assert_eq!(
+                Ed25519PubKeyHash::from_bech32(
+                    "pool",
+                    "pool1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkguxe990q"
+                )
+                .unwrap(),
+                cip19_key_hash()
+            );
+        }
+
+        #[test]
+        fn pool_ids() {
+            let pool_id = PoolId(cip19_key_hash());
+
+            assert_eq!(
+                pool_id.to_bech32().unwrap(),
+                "pool1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkguxe990q"
+            );
+            assert_bech32(
+                pool_id.clone(),
+                "pool1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkguxe990q",
+            );
+            assert_eq!(
+                PoolId::from_str("9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e")
+                    .unwrap(),
+                pool_id
+            );
+            assert!(PoolId::from_bytes(vec![0; 32]).is_err());
+            assert!(PoolId::from_str(
+                "addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg"
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn vrf_key_hashes() {
+            let hex = "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542fc37b1b5d";
+
+            assert_bech32(VrfKeyHash::from_str(hex).unwrap(), hex);
+            assert!(VrfKeyHash::from_str(
+                "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e"
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn cip129_credentials() {
+            assert_bech32(
+                DRepCredential(Credential::PubKey(cip19_key_hash())),
+                "drep1y22fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzersjmh0p6",
+            );
+            assert_bech32(
+                DRepCredential(script_credential()),
+                "drep1y0phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtc9fgr8u",
+            );
+            assert_bech32(
+                ColdCommitteeCredential(Credential::PubKey(cip19_key_hash())),
+                "cc_cold1z22fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerskn3eg7",
+            );
+            assert_bech32(
+                HotCommitteeCredential(script_credential()),
+                "cc_hot1q0phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcha6f53",
+            );
+        }
+
+        #[test]
+        fn cip129_governance_action_id() {
+            assert_bech32(
+                GovernanceActionId {
+                    tx_id: TransactionHash::from_bytes(vec![0; 32]).unwrap(),
+                    gov_action_id: BigInt::from(17),
+                },
+                "gov_action1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpzklpgpf",
+            );
+        }
+
+        #[test]
+        fn invalid_governance_action_ids() {
+            let tx_id = TransactionHash::from_bytes(vec![0; 32]).unwrap();
+
+            for index in [BigInt::from(-1), BigInt::from(65536)] {
+                let val = GovernanceActionId {
+                    tx_id: tx_id.clone(),
+                    gov_action_id: index,
+                };
+
+                assert!(val.to_bech32().is_err());
+                assert!(val.to_string().starts_with("INVALID GOVERNANCE ACTION ID"));
+            }
+
+            // Index 65536, encoded in 3 bytes
+            assert!(GovernanceActionId::from_str(
+                "gov_action1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqqq4eewah"
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn wrong_prefixes() {
+            // A cold committee credential is not a hot one, despite the same hash
+            assert!(HotCommitteeCredential::from_str(
+                "cc_cold1z22fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerskn3eg7"
+            )
+            .is_err());
+            assert!(StakePubKeyHash::from_str(
+                "addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg"
+            )
+            .is_err());
+            assert!(ScriptHash::from_str(
+                "addr_vkh1jjfnzhxe966a33psfenm0ct2udkkr569qf55v4uprgkgu8zsvmg"
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn wrong_cip129_header() {
+            let bytes = [vec![0x12], cip19_key_hash().0 .0].concat();
+            let bech32 = bech32::encode("drep", bech32::ToBase32::to_base32(&bytes)).unwrap();
+
+            assert!(DRepCredential::from_str(&bech32).is_err());
+            assert!(Ed25519PubKeyHash::from_str("addr_vkh1qqqqqqqqqqqqqq").is_err());
+            assert!(ColdCommitteeCredential::from_str(&LedgerBytes(bytes).to_string()).is_err());
+        }
+
+        proptest! {
+            #[test]
+            fn ed25519_pub_key_hash(val in arb_ed25519_pub_key_hash()) {
+                assert_eq!(val, Ed25519PubKeyHash::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn payment_pub_key_hash(val in arb_ed25519_pub_key_hash().prop_map(PaymentPubKeyHash)) {
+                assert_eq!(val, PaymentPubKeyHash::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn stake_pub_key_hash(val in arb_ed25519_pub_key_hash().prop_map(StakePubKeyHash)) {
+                assert_eq!(val, StakePubKeyHash::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn pool_id(val in arb_pool_id()) {
+                assert_eq!(val.clone(), PoolId::from_str(&val.to_string())?);
+                assert_eq!(val, PoolId::from_hex(&val.to_hex())?);
+            }
+
+            #[test]
+            fn vrf_key_hash(val in arb_vrf_key_hash()) {
+                assert_eq!(val, VrfKeyHash::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn script_hash(val in arb_script_hash()) {
+                assert_eq!(val, ScriptHash::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn d_rep_credential(val in arb_d_rep_credential()) {
+                assert_eq!(val, DRepCredential::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn cold_committee_credential(val in arb_cold_committee_credential()) {
+                assert_eq!(val, ColdCommitteeCredential::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn hot_committee_credential(val in arb_hot_committee_credential()) {
+                assert_eq!(val, HotCommitteeCredential::from_str(&val.to_string())?);
+            }
+
+            #[test]
+            fn governance_action_id(tx_id in arb_transaction_hash(), index in any::<u16>()) {
+                let val = GovernanceActionId::new(tx_id, index);
+
+                assert_eq!(val, GovernanceActionId::from_str(&val.to_string())?);
+            }
         }
     }
 }
@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod keys_tests {
+    use std::str::FromStr;
+
+    use cardano_serialization_lib as csl;
+    use plutus_ledger_api::{
+        csl::{csl_to_pla::FromCSL, pla_to_csl::TryToCSL},
+        goldens::v2::sample_transaction_info,
+        keys::{
+            bip32::{harden, Bip32PrivateKey},
+            cip1852::{Account, Role},
+            ed25519::{Ed25519PrivateKey, Ed25519PubKey, Ed25519Signature},
+            mnemonic::{Mnemonic, MnemonicError},
+            witness::{verify_witnesses, VKeyWitness, WitnessError},
+        },
+        network::Network,
+        v1::{
+            address::{Address, Credential, RewardAddress, StakingCredential},
+            crypto::{Ed25519PubKeyHash, LedgerBytes, PaymentPubKeyHash, StakePubKeyHash},
+            transaction::TransactionHash,
+        },
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    /// Mnemonic of the test vectors of CIP-19
+    const CIP19_MNEMONIC: &str =
+        "test walk nut penalty hip pave soap entry language right filter choice";
+
+    fn hex(s: &str) -> Vec<u8> {
+        data_encoding::HEXLOWER.decode(s.as_bytes()).unwrap()
+    }
+
+    fn key_hash(s: &str) -> Ed25519PubKeyHash {
+        Ed25519PubKeyHash(LedgerBytes(hex(s)))
+    }
+
+    /// Test vectors of BIP-39 (without passphrase)
+    fn mnemonic_vectors() -> Vec<(Vec<u8>, &'static str)> {
+        vec![
+            (
+                vec![0x00; 16],
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            ),
+            (
+                vec![0x7f; 16],
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            ),
+            (
+                vec![0x80; 16],
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            ),
+            (
+                vec![0xff; 16],
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            ),
+            (
+                vec![0x00; 32],
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_mnemonic_vectors() {
+        for (entropy, words) in mnemonic_vectors() {
+            let mnemonic = Mnemonic::from_entropy(entropy.clone()).unwrap();
+
+            assert_eq!(mnemonic.to_string(), words);
+            assert_eq!(Mnemonic::from_str(words).unwrap().entropy(), entropy);
+        }
+    }
+
+    #[test]
+    fn test_invalid_mnemonics() {
+        assert!(matches!(
+            Mnemonic::from_str(&["abandon"; 12].join(" ")),
+            Err(MnemonicError::InvalidChecksum)
+        ));
+        assert!(matches!(
+            Mnemonic::from_str(&["abandon"; 11].join(" ")),
+            Err(MnemonicError::InvalidWordCount(11))
+        ));
+        assert!(matches!(
+            Mnemonic::from_str(&CIP19_MNEMONIC.replace("walk", "wakl")),
+            Err(MnemonicError::UnknownWord(word)) if word == "wakl"
+        ));
+        assert!(matches!(
+            Mnemonic::from_entropy(vec![0; 17]),
+            Err(MnemonicError::InvalidEntropyLength(17))
+        ));
+        assert_eq!(
+            format!("{:?}", Mnemonic::from_str(CIP19_MNEMONIC).unwrap()),
+            "Mnemonic(12 words)"
+        );
+    }
+
+    /// The payment key of the CIP-19 test vectors is the first payment key of the mnemonic, but
+    /// their stake key is not derived from it
+    #[test]
+    fn test_cip19_wallet() {
+        let mnemonic = Mnemonic::from_str(CIP19_MNEMONIC).unwrap();
+        let account = Account::from_mnemonic(&mnemonic, "", 0);
+        let payment_key_hash = key_hash("9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e");
+        let stake_key_hash = Ed25519PubKeyHash::from_csl(
+            &csl::Bip32PrivateKey::from_bip39_entropy(mnemonic.entropy(), &[])
+                .derive(harden(1852))
+                .derive(harden(1815))
+                .derive(harden(0))
+                .derive(2)
+                .derive(0)
+                .to_raw_key()
+                .to_public()
+                .hash(),
+        );
+
+        assert_eq!(
+            account.payment_pub_key_hash(0),
+            PaymentPubKeyHash(payment_key_hash.clone())
+        );
+        assert_eq!(
+            account.stake_pub_key_hash(),
+            StakePubKeyHash(stake_key_hash.clone())
+        );
+        assert_eq!(
+            account.enterprise_address(0),
+            Address::from_str("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+                .unwrap()
+        );
+        assert_eq!(
+            account.base_address(0),
+            Address {
+                credential: Credential::PubKey(payment_key_hash),
+                staking_credential: Some(StakingCredential::Hash(Credential::PubKey(
+                    stake_key_hash.clone()
+                ))),
+            }
+        );
+        assert_eq!(
+            account.reward_address(&Network::preprod()),
+            RewardAddress {
+                network_tag: 0,
+                credential: Credential::PubKey(stake_key_hash),
+            }
+        );
+    }
+
+    #[test]
+    fn test_preview_reward_address_roundtrip() {
+        let account = Account::from_mnemonic(&Mnemonic::from_str(CIP19_MNEMONIC).unwrap(), "", 0);
+        let preview = Network::preview();
+        let reward_address = account.reward_address(&preview);
+        let bech32 = reward_address.to_bech32(&preview).unwrap();
+
+        assert!(bech32.starts_with("stake_test1"));
+        assert_eq!(bech32, reward_address.to_string());
+        assert_eq!(RewardAddress::from_str(&bech32).unwrap(), reward_address);
+        assert_eq!(
+            RewardAddress::from_bech32_with_network(&bech32, &preview).unwrap(),
+            reward_address
+        );
+        assert!(RewardAddress::from_bech32_with_network(&bech32, &Network::mainnet()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_private_keys() {
+        let key = Account::from_mnemonic(&Mnemonic::from_str(CIP19_MNEMONIC).unwrap(), "", 0)
+            .payment_key(0);
+
+        assert_eq!(
+            Bip32PrivateKey::from_bytes(key.as_bytes().to_vec()).unwrap(),
+            key
+        );
+        assert!(Bip32PrivateKey::from_bytes(key.as_bytes()[..64].to_vec()).is_err());
+
+        let mut bytes = key.as_bytes().to_vec();
+        bytes[0] |= 1;
+        assert!(Bip32PrivateKey::from_bytes(bytes).is_err());
+        assert!(Ed25519PubKey::from_bytes(vec![0; 31]).is_err());
+    }
+
+    /// Test vector 1 of RFC 8032
+    #[test]
+    fn test_ed25519_vector() {
+        let key = Ed25519PrivateKey::from_normal_bytes(hex(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        ))
+        .unwrap();
+        let signature = Ed25519Signature(LedgerBytes(hex(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        )));
+
+        assert_eq!(
+            key.public_key(),
+            Ed25519PubKey::from_bytes(hex(
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+            ))
+            .unwrap()
+        );
+        assert_eq!(key.sign(&[]), signature);
+        assert!(key.public_key().verify(&[], &signature));
+        assert!(!key.public_key().verify(&[0], &signature));
+        assert_eq!(format!("{:?}", key), "Ed25519PrivateKey(..)");
+    }
+
+    fn account_witnesses(tx_hash: &TransactionHash) -> (Vec<PaymentPubKeyHash>, Vec<VKeyWitness>) {
+        let account = Account::from_mnemonic(&Mnemonic::from_str(CIP19_MNEMONIC).unwrap(), "", 0);
+
+        (0..3)
+            .map(|index| {
+                (
+                    account.payment_pub_key_hash(index),
+                    VKeyWitness::from_bip32(tx_hash, &account.payment_key(index)),
+                )
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn test_verify_witnesses() {
+        let tx_info = sample_transaction_info();
+        let (signatories, witnesses) = account_witnesses(&tx_info.id);
+        let tx_info = plutus_ledger_api::v2::transaction::TransactionInfo {
+            signatories: signatories.clone(),
+            ..tx_info
+        };
+
+        assert!(tx_info.verify_witnesses(&witnesses).is_ok());
+
+        // Witnesses of keys that are not required signers are allowed
+        assert!(verify_witnesses(&tx_info.id, &signatories[..1], &witnesses).is_ok());
+
+        assert!(matches!(
+            tx_info.verify_witnesses(&witnesses[1..]),
+            Err(WitnessError::MissingSignature(signatory)) if signatory == signatories[0]
+        ));
+
+        let other_tx_hash = TransactionHash::from_bytes(vec![1; 32]).unwrap();
+        assert!(matches!(
+            verify_witnesses(&other_tx_hash, &signatories, &witnesses),
+            Err(WitnessError::InvalidSignature(vkey)) if vkey == witnesses[0].vkey
+        ));
+
+        let mut invalid_witnesses = witnesses.clone();
+        invalid_witnesses[2].signature.0 .0[0] ^= 1;
+        assert!(matches!(
+            tx_info.verify_witnesses(&invalid_witnesses),
+            Err(WitnessError::InvalidSignature(vkey)) if vkey == witnesses[2].vkey
+        ));
+    }
+
+    #[test]
+    fn test_witnesses_csl_roundtrip() {
+        let (_, witnesses) = account_witnesses(&sample_transaction_info().id);
+
+        assert_eq!(
+            Vec::<VKeyWitness>::from_csl(&witnesses.try_to_csl().unwrap()),
+            witnesses
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_signing_matches_csl(
+            seed in vec(any::<u8>(), 32),
+            entropy in vec(any::<u8>(), 16),
+            tx_hash in vec(any::<u8>(), 32),
+        ) {
+            let tx_hash = TransactionHash::from_bytes(tx_hash)?;
+            let csl_tx_hash: csl::TransactionHash = tx_hash.try_to_csl()?;
+            let keys = [
+                Ed25519PrivateKey::from_normal_bytes(seed)?,
+                Bip32PrivateKey::from_bip39_entropy(&entropy, &[]).derive(0).to_private_key(),
+            ];
+
+            for key in keys {
+                let witness = VKeyWitness::new(&tx_hash, &key);
+                let csl_witness = csl::make_vkey_witness(&csl_tx_hash, &key.try_to_csl()?);
+
+                prop_assert_eq!(&witness, &VKeyWitness::from_csl(&csl_witness));
+                prop_assert!(witness.verify(&tx_hash));
+            }
+        }
+
+        #[test]
+        fn test_mnemonic_roundtrip(entropy in (4usize..=8).prop_flat_map(|words| vec(any::<u8>(), words * 4))) {
+            let mnemonic = Mnemonic::from_entropy(entropy)?;
+
+            prop_assert_eq!(Mnemonic::from_str(&mnemonic.to_string())?, mnemonic);
+        }
+
+        #[test]
+        fn test_derivation_matches_csl(
+            entropy in vec(any::<u8>(), 16),
+            passphrase in vec(any::<u8>(), 0..8),
+            account in any::<u32>(),
+            role in prop_oneof![Just(Role::External), Just(Role::Internal), Just(Role::Staking)],
+            index in any::<u32>(),
+        ) {
+            let key = Account::from_root_key(
+                &Bip32PrivateKey::from_bip39_entropy(&entropy, &passphrase),
+                account,
+            )
+            .derive(role, index);
+
+            let csl_key = csl::Bip32PrivateKey::from_bip39_entropy(&entropy, &passphrase)
+                .derive(harden(1852))
+                .derive(harden(1815))
+                .derive(harden(account))
+                .derive(role.into())
+                .derive(index);
+
+            prop_assert_eq!(key.as_bytes(), csl_key.as_bytes());
+            prop_assert_eq!(
+                key.public_key(),
+                Ed25519PubKey::from_csl(&csl_key.to_raw_key().to_public())
+            );
+            prop_assert_eq!(
+                key.public_key().hash(),
+                Ed25519PubKeyHash::from_csl(&csl_key.to_raw_key().to_public().hash())
+            );
+        }
+    }
+}
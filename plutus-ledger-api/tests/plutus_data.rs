@@ -530,4 +530,159 @@ mod plutusdata_roundtrip_tests {
             }
         }
     }
+
+    mod prop_derive {
+        use std::marker::PhantomData;
+
+        use super::from_to_plutus_data;
+        use num_bigint::BigInt;
+        use plutus_ledger_api::generators::correct::primitive::arb_integer;
+        use plutus_ledger_api::plutus_data::{IsPlutusData, PlutusData, PlutusDataError};
+        use proptest::prelude::*;
+
+        /// Type level tag without any IsPlutusData instance
+        #[derive(Clone, Debug, PartialEq)]
+        struct Ada;
+
+        /// Phantom field, encoded as unit regardless of its type parameter
+        #[derive(Clone, Debug, PartialEq)]
+        struct Tag<T>(PhantomData<T>);
+
+        impl<T> IsPlutusData for Tag<T> {
+            fn to_plutus_data(&self) -> PlutusData {
+                ().to_plutus_data()
+            }
+
+            fn from_plutus_data(data: &PlutusData) -> Result<Self, PlutusDataError> {
+                <()>::from_plutus_data(data).map(|()| Tag(PhantomData))
+            }
+        }
+
+        /// The library is only reachable as `pla` in here: the empty module shadows the crate name,
+        /// so the derived instances only compile if they go through the `crate` attribute
+        mod renamed {
+            use ::plutus_ledger_api as pla;
+            use num_bigint::BigInt;
+            use pla::plutus_data::IsPlutusData;
+
+            use super::Tag;
+
+            mod plutus_ledger_api {}
+
+            #[derive(Clone, Debug, PartialEq, IsPlutusData)]
+            #[plutus_data(crate = "pla", bound = "")]
+            pub(super) struct Tagged<T> {
+                pub(super) amount: BigInt,
+                pub(super) tag: Tag<T>,
+            }
+
+            #[derive(Clone, Debug, PartialEq, IsPlutusData)]
+            #[is_plutus_data_derive_strategy = "Newtype"]
+            #[plutus_data(crate = "pla")]
+            pub(super) struct Wrapped<T>(pub(super) T);
+        }
+
+        use renamed::{Tagged, Wrapped};
+
+        #[derive(Clone, Debug, PartialEq, IsPlutusData)]
+        #[plutus_data(bound = "T: IsPlutusData + Clone")]
+        enum Either<T> {
+            Left(T),
+            Right { tag: Tag<T>, amount: BigInt },
+        }
+
+        proptest! {
+            #[test]
+            fn derive_with_empty_bound(amount in arb_integer()) {
+                let val = Tagged::<Ada> { amount, tag: Tag(PhantomData) };
+                assert_eq!(val, from_to_plutus_data(&val)?);
+            }
+
+            #[test]
+            fn derive_newtype_with_crate_path(amount in arb_integer()) {
+                let val = Wrapped(amount);
+                assert_eq!(val, from_to_plutus_data(&val)?);
+            }
+
+            #[test]
+            fn derive_with_custom_bound(left in arb_integer(), amount in arb_integer()) {
+                let val = Either::Left(left);
+                assert_eq!(val, from_to_plutus_data(&val)?);
+
+                let val = Either::<BigInt>::Right { tag: Tag(PhantomData), amount };
+                assert_eq!(val, from_to_plutus_data(&val)?);
+            }
+        }
+    }
+
+    mod cip68 {
+        use super::from_to_plutus_data;
+        use num_bigint::BigInt;
+        use plutus_ledger_api::cip68::Cip68Datum;
+        use plutus_ledger_api::generators::correct::{
+            primitive::{arb_integer, arb_text},
+            v1::{arb_assoc_map, arb_plutus_data},
+        };
+        use plutus_ledger_api::plutus_data::{IsPlutusData, PlutusData};
+        use proptest::prelude::*;
+
+        #[test]
+        fn cip68_datum_layout() {
+            let mut datum = Cip68Datum::new(BigInt::from(1));
+            datum.insert("name", String::from("Token"));
+            datum.insert("decimals", BigInt::from(6));
+
+            assert_eq!(
+                datum.to_plutus_data(),
+                PlutusData::constr(
+                    0,
+                    vec![
+                        PlutusData::map(vec![
+                            (
+                                PlutusData::bytes(b"name".to_vec()),
+                                PlutusData::bytes(b"Token".to_vec())
+                            ),
+                            (
+                                PlutusData::bytes(b"decimals".to_vec()),
+                                PlutusData::integer(6)
+                            ),
+                        ]),
+                        PlutusData::integer(1),
+                        PlutusData::constr(0, Vec::new()),
+                    ]
+                )
+            );
+        }
+
+        #[test]
+        fn cip68_datum_accessors() {
+            let mut datum = Cip68Datum::new(BigInt::from(1));
+            datum.insert("name", String::from("Token"));
+            datum.insert("image", String::from("ipfs://image"));
+            datum.insert("decimals", BigInt::from(6));
+
+            assert_eq!(datum.name(), Some(String::from("Token")));
+            assert_eq!(datum.image(), Some(String::from("ipfs://image")));
+            assert_eq!(datum.decimals(), Some(BigInt::from(6)));
+            assert_eq!(datum.description(), None);
+
+            assert_eq!(
+                datum.insert("name", String::from("Other")),
+                Some(PlutusData::bytes(b"Token".to_vec()))
+            );
+            assert_eq!(datum.name(), Some(String::from("Other")));
+        }
+
+        proptest! {
+            #[test]
+            fn cip68_datum(
+                metadata in arb_assoc_map(arb_text(), arb_plutus_data()),
+                version in arb_integer(),
+                extra in arb_plutus_data()
+            ) {
+                let val = Cip68Datum { metadata, version, extra };
+                assert_eq!(val, from_to_plutus_data(&val)?);
+            }
+        }
+    }
 }
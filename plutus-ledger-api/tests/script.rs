@@ -0,0 +1,437 @@
+#[cfg(test)]
+mod plutus_script_tests {
+    use cardano_serialization_lib as csl;
+    use plutus_ledger_api::{
+        csl::{csl_to_pla::FromCSL, pla_to_csl::TryToCSL},
+        generators::correct::v1::{arb_plutus_script, arb_staking_credential},
+        network::Network,
+        v1::{
+            address::{Address, Credential, StakingCredential},
+            crypto::LedgerBytes,
+            script::{PlutusLanguage, PlutusScript, PlutusScriptError, ScriptHash},
+            value::CurrencySymbol,
+        },
+    };
+    use proptest::prelude::*;
+
+    /// Always succeeding Plutus V2 validator, as the `cborHex` of a cardano-cli text envelope
+    const ALWAYS_SUCCEEDS_DOUBLE_CBOR: &str = "49480100002221200101";
+
+    /// The same validator, as the `compiledCode` of a CIP-57 blueprint
+    const ALWAYS_SUCCEEDS_SINGLE_CBOR: &str = "480100002221200101";
+
+    fn always_succeeds() -> PlutusScript {
+        PlutusScript::from_cbor_hex(PlutusLanguage::V2, ALWAYS_SUCCEEDS_SINGLE_CBOR).unwrap()
+    }
+
+    #[test]
+    fn normalize_cbor_wrapping() {
+        let double =
+            PlutusScript::from_cbor_hex(PlutusLanguage::V2, ALWAYS_SUCCEEDS_DOUBLE_CBOR).unwrap();
+
+        assert_eq!(double, always_succeeds());
+        assert_eq!(
+            always_succeeds().bytes,
+            LedgerBytes(vec![0x48, 0x01, 0x00, 0x00, 0x22, 0x21, 0x20, 0x01, 0x01])
+        );
+        assert_eq!(
+            PlutusScript::from_flat(PlutusLanguage::V2, &always_succeeds().bytes.0[1..]),
+            always_succeeds()
+        );
+    }
+
+    #[test]
+    fn invalid_cbor() {
+        assert!(matches!(
+            PlutusScript::from_cbor_hex(PlutusLanguage::V2, "0100002221200101"),
+            Err(PlutusScriptError::Cbor(_))
+        ));
+        assert!(matches!(
+            PlutusScript::from_cbor_hex(PlutusLanguage::V2, "48010000222120010100"),
+            Err(PlutusScriptError::TrailingBytes)
+        ));
+        assert!(matches!(
+            PlutusScript::from_cbor_hex(PlutusLanguage::V2, "not hex"),
+            Err(PlutusScriptError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn hash_depends_on_language() {
+        let v1 = PlutusScript {
+            language: PlutusLanguage::V1,
+            ..always_succeeds()
+        };
+
+        assert_ne!(v1.hash(), always_succeeds().hash());
+    }
+
+    #[test]
+    fn script_address() {
+        let script = always_succeeds();
+        let enterprise = script.address(None);
+
+        assert_eq!(
+            enterprise.credential,
+            Credential::Script(script.validator_hash())
+        );
+        assert!(enterprise
+            .with_extra_info(&Network::mainnet())
+            .to_bech32()
+            .unwrap()
+            .starts_with("addr1w"));
+
+        let base = script.address(Some(StakingCredential::Hash(Credential::Script(
+            script.validator_hash(),
+        ))));
+        assert_eq!(
+            Address::from_bech32(
+                &base
+                    .with_extra_info(&Network::preview())
+                    .to_bech32()
+                    .unwrap()
+            )
+            .unwrap()
+            .0,
+            base
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn hash_matches_csl(script in arb_plutus_script()) {
+            let csl_script: csl::PlutusScript = script.try_to_csl()?;
+
+            prop_assert_eq!(script.hash(), ScriptHash::from_csl(&csl_script.hash()));
+        }
+
+        #[test]
+        fn csl_roundtrip(script in arb_plutus_script()) {
+            prop_assert_eq!(PlutusScript::from_csl(&script.try_to_csl()?), script);
+        }
+
+        #[test]
+        fn double_cbor_roundtrip(script in arb_plutus_script()) {
+            let csl_script: csl::PlutusScript = script.try_to_csl()?;
+
+            prop_assert_eq!(
+                PlutusScript::from_cbor(script.language, &csl_script.to_bytes())?,
+                script.clone()
+            );
+            prop_assert_eq!(PlutusScript::from_cbor(script.language, &script.bytes.0)?, script);
+        }
+
+        #[test]
+        fn currency_symbol(script in arb_plutus_script()) {
+            prop_assert_eq!(
+                script.currency_symbol(),
+                CurrencySymbol::NativeToken(script.minting_policy_hash())
+            );
+            prop_assert_eq!(script.minting_policy_hash().0, script.hash());
+        }
+
+        #[test]
+        fn staked_address(script in arb_plutus_script(), staking_credential in arb_staking_credential()) {
+            let address = script.address(Some(staking_credential.clone()));
+
+            prop_assert_eq!(address.credential, Credential::Script(script.validator_hash()));
+            prop_assert_eq!(address.staking_credential, Some(staking_credential));
+        }
+    }
+}
+
+#[cfg(test)]
+mod native_script_tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use cardano_serialization_lib as csl;
+    use cryptoxide::hashing::blake2b::Blake2b;
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        csl::{
+            csl_to_pla::{FromCSL, TryFromCSL, TryToPLA},
+            pla_to_csl::TryToCSL,
+        },
+        generators::correct::{
+            v1::{arb_native_script, arb_plutus_script},
+            v2::arb_transaction_output,
+        },
+        native_script::{NativeScript, NativeScriptError},
+        network::Network,
+        slot_config::SlotConfig,
+        v1::{
+            address::Slot,
+            crypto::{Ed25519PubKeyHash, LedgerBytes},
+            interval::{Extended, LowerBound, PlutusInterval, UpperBound},
+            script::{Script, ScriptHash},
+            transaction::POSIXTime,
+        },
+        v2::transaction::{
+            LedgerTransactionOutput, LedgerTransactionOutputWithExtraInfo, TransactionOutput,
+            TransactionOutputWithExtraInfo,
+        },
+    };
+    use proptest::prelude::*;
+
+    fn key_hash(byte: u8) -> Ed25519PubKeyHash {
+        Ed25519PubKeyHash::from_bytes(vec![byte; 28]).unwrap()
+    }
+
+    fn signers(bytes: &[u8]) -> BTreeSet<Ed25519PubKeyHash> {
+        bytes.iter().map(|byte| key_hash(*byte)).collect()
+    }
+
+    fn slot(slot: u64) -> Slot {
+        Slot(BigInt::from(slot))
+    }
+
+    #[test]
+    fn evaluate_signatures() {
+        let multisig = NativeScript::AtLeast(
+            2,
+            vec![
+                NativeScript::Sig(key_hash(1)),
+                NativeScript::Sig(key_hash(2)),
+                NativeScript::Sig(key_hash(3)),
+            ],
+        );
+
+        assert!(multisig.evaluate(&signers(&[1, 3]), None, None));
+        assert!(multisig.evaluate(&signers(&[1, 2, 3]), None, None));
+        assert!(!multisig.evaluate(&signers(&[2, 4]), None, None));
+        assert!(NativeScript::All(vec![]).evaluate(&signers(&[]), None, None));
+        assert!(!NativeScript::Any(vec![]).evaluate(&signers(&[]), None, None));
+        assert!(NativeScript::AtLeast(0, vec![]).evaluate(&signers(&[]), None, None));
+    }
+
+    #[test]
+    fn evaluate_time_locks() {
+        let before = NativeScript::Before(100);
+        let after = NativeScript::After(100);
+        let signers = signers(&[]);
+
+        assert!(before.evaluate(&signers, None, Some(&slot(100))));
+        assert!(!before.evaluate(&signers, None, Some(&slot(101))));
+        assert!(!before.evaluate(&signers, Some(&slot(0)), None));
+
+        assert!(after.evaluate(&signers, Some(&slot(100)), None));
+        assert!(!after.evaluate(&signers, Some(&slot(99)), None));
+        assert!(!after.evaluate(&signers, None, Some(&slot(0))));
+    }
+
+    #[test]
+    fn evaluate_in_time_range() {
+        let slot_config = SlotConfig::preview();
+        let time_lock = NativeScript::All(vec![
+            NativeScript::After(10),
+            NativeScript::Before(20),
+            NativeScript::Sig(key_hash(1)),
+        ]);
+        let range = |from: u64, to: u64| PlutusInterval {
+            from: LowerBound {
+                bound: Extended::Finite(slot_config.slot_to_posix_time(&slot(from))),
+                closed: true,
+            },
+            to: UpperBound {
+                bound: Extended::Finite(slot_config.slot_to_posix_time(&slot(to))),
+                closed: false,
+            },
+        };
+
+        assert!(time_lock
+            .evaluate_in_time_range(&signers(&[1]), &range(10, 20), &slot_config)
+            .unwrap());
+        assert!(!time_lock
+            .evaluate_in_time_range(&signers(&[1]), &range(9, 20), &slot_config)
+            .unwrap());
+        assert!(!time_lock
+            .evaluate_in_time_range(&signers(&[2]), &range(10, 20), &slot_config)
+            .unwrap());
+        assert!(!time_lock
+            .evaluate_in_time_range(
+                &signers(&[1]),
+                &PlutusInterval {
+                    to: UpperBound {
+                        bound: Extended::Finite(POSIXTime(
+                            slot_config.slot_to_posix_time(&slot(21)).0 + 1
+                        )),
+                        closed: false,
+                    },
+                    ..range(10, 20)
+                },
+                &slot_config
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn invalid_cbor() {
+        // [6, 0] has an unknown tag
+        assert!(matches!(
+            NativeScript::from_cbor(&[0x82, 0x06, 0x00]),
+            Err(NativeScriptError::UnknownTag(6))
+        ));
+        // [3, [], 0] is missing its number of required scripts
+        assert!(matches!(
+            NativeScript::from_cbor(&[0x82, 0x03, 0x80]),
+            Err(NativeScriptError::InvalidArrayLength { expected: 3, .. })
+        ));
+        // [4, 0] followed by a stray byte
+        assert!(matches!(
+            NativeScript::from_cbor(&[0x82, 0x04, 0x00, 0x00]),
+            Err(NativeScriptError::TrailingBytes)
+        ));
+    }
+
+    #[test]
+    fn non_canonical_cbor() {
+        // [1, [_ [4, 0]]] would hash differently than its canonical encoding [1, [[4, 0]]]
+        assert!(matches!(
+            NativeScript::from_cbor(&[0x82, 0x01, 0x9f, 0x82, 0x04, 0x00, 0xff]),
+            Err(NativeScriptError::NonCanonical)
+        ));
+        // [4, 0] with the slot encoded on two bytes
+        assert!(matches!(
+            NativeScript::from_cbor(&[0x82, 0x04, 0x18, 0x00]),
+            Err(NativeScriptError::NonCanonical)
+        ));
+    }
+
+    #[test]
+    fn hash_of_original_bytes() {
+        // [1, [[4, 0]]]
+        let bytes = [0x82, 0x01, 0x81, 0x82, 0x04, 0x00];
+        let mut hash = vec![0; 28];
+        Blake2b::<224>::new()
+            .update(&[0])
+            .update(&bytes)
+            .finalize_at(&mut hash);
+
+        let script = NativeScript::from_cbor(&bytes).unwrap();
+        assert_eq!(script, NativeScript::All(vec![NativeScript::After(0)]));
+        assert_eq!(script.hash(), ScriptHash(LedgerBytes(hash)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn cardano_cli_json() {
+        let json = r#"{
+            "type": "all",
+            "scripts": [
+                { "type": "before", "slot": 600 },
+                { "type": "after", "slot": 300 },
+                {
+                    "type": "atLeast",
+                    "required": 1,
+                    "scripts": [
+                        {
+                            "type": "sig",
+                            "keyHash": "966e394a544f242081e41d1965137b1bb412ac230d40ed5407821c37"
+                        },
+                        { "type": "any", "scripts": [] }
+                    ]
+                }
+            ]
+        }"#;
+
+        let script: NativeScript = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            script,
+            NativeScript::All(vec![
+                NativeScript::Before(600),
+                NativeScript::After(300),
+                NativeScript::AtLeast(
+                    1,
+                    vec![
+                        NativeScript::Sig(
+                            Ed25519PubKeyHash::from_bytes(
+                                data_encoding::HEXLOWER
+                                    .decode(
+                                        b"966e394a544f242081e41d1965137b1bb412ac230d40ed5407821c37"
+                                    )
+                                    .unwrap()
+                            )
+                            .unwrap()
+                        ),
+                        NativeScript::Any(vec![])
+                    ]
+                )
+            ])
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json).unwrap(),
+            serde_json::to_value(&script).unwrap()
+        );
+        assert!(
+            serde_json::from_str::<NativeScript>(r#"{ "type": "sig", "keyHash": "00" }"#).is_err()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn cbor_matches_csl(script in arb_native_script()) {
+            let csl_script: csl::NativeScript = script.try_to_csl()?;
+
+            prop_assert_eq!(script.to_cbor(), csl_script.to_bytes());
+            prop_assert_eq!(script.hash(), ScriptHash::from_csl(&csl_script.hash()));
+        }
+
+        #[test]
+        fn cbor_roundtrip(script in arb_native_script()) {
+            prop_assert_eq!(NativeScript::from_cbor(&script.to_cbor())?, script);
+        }
+
+        #[test]
+        fn csl_roundtrip(script in arb_native_script()) {
+            prop_assert_eq!(NativeScript::from_csl(&script.try_to_csl()?), script);
+        }
+
+        #[test]
+        fn script_ref_roundtrip(native in arb_native_script(), plutus in arb_plutus_script()) {
+            for script in [Script::Native(native), Script::Plutus(plutus)] {
+                let script_ref: csl::ScriptRef = script.try_to_csl()?;
+
+                prop_assert_eq!(Script::from_csl(&script_ref), script);
+            }
+        }
+
+        #[test]
+        fn output_reference_script_roundtrip(native in arb_native_script(), output in arb_transaction_output()) {
+            let script = Script::Native(native);
+            let scripts = BTreeMap::from([(script.hash(), script.clone())]);
+            let output = TransactionOutput {
+                reference_script: Some(script.hash()),
+                ..output
+            };
+            let network = Network::mainnet();
+            let data_cost = csl::DataCost::new_coins_per_byte(&csl::BigNum::from(4310u64));
+
+            let csl_output: csl::TransactionOutput =
+                TransactionOutputWithExtraInfo::new(&output, &scripts, &network, &data_cost)
+                    .try_to_csl()?;
+            prop_assert!(csl_output.script_ref().unwrap().is_native_script());
+
+            let ledger_output: LedgerTransactionOutput = csl_output.try_to_pla()?;
+            prop_assert_eq!(&ledger_output.reference_script, &Some(script));
+            prop_assert_eq!(
+                TransactionOutput::try_from_csl(&csl_output)?.reference_script,
+                output.reference_script
+            );
+
+            let roundtripped: csl::TransactionOutput =
+                LedgerTransactionOutputWithExtraInfo::new(&ledger_output, &network, &data_cost)
+                    .try_to_csl()?;
+            prop_assert_eq!(roundtripped.to_bytes(), csl_output.to_bytes());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn json_roundtrip(script in arb_native_script()) {
+            prop_assert_eq!(
+                serde_json::from_str::<NativeScript>(&serde_json::to_string(&script)?)?,
+                script
+            );
+        }
+    }
+}
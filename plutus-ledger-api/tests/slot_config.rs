@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod slot_config_tests {
+    use cardano_serialization_lib as csl;
+    use num_bigint::BigInt;
+    use plutus_ledger_api::{
+        generators::correct::{
+            primitive::arb_natural,
+            v1::{arb_plutus_interval, arb_posix_time},
+        },
+        slot_config::SlotConfig,
+        v1::{
+            address::Slot,
+            interval::{Extended, LowerBound, PlutusInterval, UpperBound},
+            transaction::POSIXTime,
+        },
+    };
+    use proptest::{option, prelude::*};
+
+    fn arb_slot_config() -> impl Strategy<Value = SlotConfig> {
+        (arb_posix_time(), arb_natural(1), 1..5000u64).prop_map(
+            |(zero_time, zero_slot, slot_length)| {
+                SlotConfig::new(zero_time, Slot(zero_slot), slot_length)
+            },
+        )
+    }
+
+    fn arb_small_posix_time() -> impl Strategy<Value = POSIXTime> {
+        (0..1000).prop_map(|time| POSIXTime(BigInt::from(time)))
+    }
+
+    fn arb_slot() -> impl Strategy<Value = Slot> {
+        arb_natural(2).prop_map(Slot)
+    }
+
+    #[test]
+    fn test_mainnet_preset() {
+        let config = SlotConfig::mainnet();
+
+        assert_eq!(
+            config.slot_to_posix_time(&Slot(BigInt::from(4492800))),
+            POSIXTime(BigInt::from(1596059091000u64))
+        );
+        assert_eq!(
+            config.posix_time_to_slot(&POSIXTime(BigInt::from(1700000000999u64))),
+            Slot(BigInt::from(108433709))
+        );
+    }
+
+    #[test]
+    fn test_boundary_slots() {
+        let config = SlotConfig::new(POSIXTime(BigInt::from(0)), Slot(BigInt::from(0)), 1000);
+        let range = PlutusInterval {
+            from: LowerBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(1000))),
+                closed: false,
+            },
+            to: UpperBound {
+                bound: Extended::Finite(POSIXTime(BigInt::from(5000))),
+                closed: true,
+            },
+        };
+
+        assert_eq!(
+            config.posix_time_range_to_validity(&range).unwrap(),
+            (Some(Slot(BigInt::from(2))), Some(Slot(BigInt::from(5))))
+        );
+    }
+
+    #[test]
+    fn test_tx_body_valid_range() {
+        let config = SlotConfig::preview();
+        let mut tx_body = csl::TransactionBody::new_tx_body(
+            &csl::TransactionInputs::new(),
+            &csl::TransactionOutputs::new(),
+            &csl::BigNum::zero(),
+        );
+
+        let range = config.validity_to_posix_time_range(
+            Some(&Slot(BigInt::from(100))),
+            Some(&Slot(BigInt::from(200))),
+        );
+        config
+            .set_tx_body_valid_range(&mut tx_body, &range)
+            .unwrap();
+
+        assert_eq!(
+            tx_body.validity_start_interval_bignum(),
+            Some(csl::BigNum::from(100u64))
+        );
+        assert_eq!(tx_body.ttl_bignum(), Some(csl::BigNum::from(200u64)));
+        assert_eq!(config.tx_body_valid_range(&tx_body), range);
+    }
+
+    proptest! {
+      #[test]
+      fn test_slot_roundtrip(config in arb_slot_config(), slot in arb_slot()) {
+        prop_assert_eq!(config.posix_time_to_slot(&config.slot_to_posix_time(&slot)), slot);
+      }
+
+      #[test]
+      fn test_time_within_slot(config in arb_slot_config(), time in arb_posix_time()) {
+        let slot = config.posix_time_to_slot(&time);
+        let start = config.slot_to_posix_time(&slot);
+
+        prop_assert!(start.0 <= time.0 && time.0 < start.0 + config.slot_length);
+      }
+
+      #[test]
+      fn test_validity_roundtrip(
+        config in arb_slot_config(),
+        validity_start in option::of(arb_slot()),
+        ttl in option::of(arb_slot())
+      ) {
+        let range = config.validity_to_posix_time_range(validity_start.as_ref(), ttl.as_ref());
+
+        prop_assert_eq!(config.posix_time_range_to_validity(&range)?, (validity_start, ttl));
+      }
+
+      #[test]
+      fn test_validity_within_range(
+        zero_time in 0..100i64,
+        slot_length in 1..50u64,
+        range in arb_plutus_interval(arb_small_posix_time(), arb_small_posix_time()),
+        time in arb_small_posix_time()
+      ) {
+        let config = SlotConfig::new(POSIXTime(BigInt::from(zero_time)), Slot(BigInt::from(0)), slot_length);
+
+        if let Ok((validity_start, ttl)) = config.posix_time_range_to_validity(&range) {
+          let valid_range = config.validity_to_posix_time_range(validity_start.as_ref(), ttl.as_ref());
+
+          prop_assert!(!valid_range.member(&time) || range.member(&time));
+        }
+      }
+    }
+}
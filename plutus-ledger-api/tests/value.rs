@@ -76,6 +76,8 @@ mod value_tests {
     }
 
     mod other_utils {
+        use std::collections::BTreeMap;
+
         use plutus_ledger_api::generators::correct::{
             primitive::arb_integer,
             v1::{arb_currency_symbol, arb_token_name, arb_value},
@@ -94,6 +96,397 @@ mod value_tests {
           fn test_insert_token_amount_roundtrip(val in arb_value(), cs in arb_currency_symbol(), tn in arb_token_name(), amount in arb_integer()){
             assert_eq!(val.insert_token(&cs, &tn, &amount).get_token_amount(&cs, &tn), amount);
           }
+
+          #[test]
+          fn test_insert_token_mut(val in arb_value(), cs in arb_currency_symbol(), tn in arb_token_name(), amount in arb_integer()){
+            let mut mutated = val.clone();
+            mutated.insert_token_mut(&cs, &tn, &amount);
+
+            assert_eq!(mutated, val.insert_token(&cs, &tn, &amount));
+          }
+
+          #[test]
+          fn test_add_token_mut(val in arb_value(), cs in arb_currency_symbol(), tn in arb_token_name(), amount in arb_integer()){
+            let mut mutated = val.clone();
+            mutated.add_token_mut(&cs, &tn, &amount);
+
+            assert_eq!(mutated, &val + Value::token_value(&cs, &tn, &amount));
+          }
+
+          #[test]
+          fn test_add_assign(x in arb_value(), y in arb_value()){
+            let mut by_ref = x.clone();
+            by_ref += &y;
+            let mut by_val = x.clone();
+            by_val += y.clone();
+
+            assert_eq!(&by_ref, &(&x + &y));
+            assert_eq!(by_val, &x + &y);
+          }
+
+          #[test]
+          fn test_sub_assign(x in arb_value(), y in arb_value()){
+            let mut by_ref = x.clone();
+            by_ref -= &y;
+            let mut by_val = x.clone();
+            by_val -= y.clone();
+
+            assert_eq!(&by_ref, &(&x + &-&y));
+            assert_eq!(by_val, &x + &-&y);
+          }
+
+          #[test]
+          fn test_map_roundtrip(val in arb_value()){
+            let map = BTreeMap::from(val.clone());
+
+            assert_eq!(Value::from(map.clone()), val);
+            assert!(val.currency_symbols().eq(map.keys()));
+            for (cs, tokens) in &map {
+              assert!(val.tokens(cs).eq(tokens.iter()));
+            }
+          }
+
+          #[test]
+          fn test_from_iter(x in arb_value(), y in arb_value()){
+            let collected: Value = x
+              .iter()
+              .chain(y.iter())
+              .map(|(cs, tn, amount)| (cs.clone(), tn.clone(), amount.clone()))
+              .collect();
+
+            assert_eq!(collected, &x + &y);
+          }
+        }
+    }
+
+    mod lattice {
+        use num_bigint::BigInt;
+        use plutus_ledger_api::generators::correct::v1::arb_value;
+        use plutus_ledger_api::v1::value::{CurrencySymbol, TokenName, Value};
+        use proptest::prelude::*;
+
+        #[test]
+        fn test_missing_tokens_are_zero() {
+            let cs = CurrencySymbol::Ada;
+            let tn = TokenName::ada();
+            let x = Value::token_value(&cs, &tn, &BigInt::from(2));
+            let y = Value::token_value(&cs, &tn, &BigInt::from(1)).insert_token(
+                &cs,
+                &TokenName::from_string("foo").unwrap(),
+                &BigInt::from(1),
+            );
+
+            assert!(!x.geq(&y));
+            assert!(!x.gt(&y));
+            assert!(!x.leq(&y));
+            assert!(!Value::new().gt(&Value::new()));
+            assert!(Value::new().geq(&Value::new()));
+        }
+
+        proptest! {
+          #[test]
+          fn test_geq_reflexive(x in arb_value()) {
+            assert!(x.geq(&x));
+            assert!(x.leq(&x));
+          }
+
+          #[test]
+          fn test_lt_irreflexive(x in arb_value()) {
+            assert!(!x.lt(&x));
+            assert!(!x.gt(&x));
+          }
+
+          #[test]
+          fn test_leq_is_flipped_geq(x in arb_value(), y in arb_value()) {
+            assert_eq!(x.leq(&y), y.geq(&x));
+            assert_eq!(x.lt(&y), y.gt(&x));
+          }
+
+          #[test]
+          fn test_leq_is_subset(x in arb_value(), y in arb_value()) {
+            assert_eq!(x.leq(&y), x.is_subset(&y));
+          }
+
+          #[test]
+          fn test_leq_sum(x in arb_value(), y in arb_value()) {
+            assert!(x.leq(&(&x + &y)));
+          }
+
+          #[test]
+          fn test_max_min_bounds(x in arb_value(), y in arb_value()) {
+            let join = x.max(&y);
+            let meet = x.min(&y);
+
+            assert!(join.geq(&x) && join.geq(&y));
+            assert!(meet.leq(&x) && meet.leq(&y));
+            assert_eq!(&join + &meet, &x + &y);
+          }
+
+          #[test]
+          fn test_split_roundtrip(x in arb_value(), y in arb_value()) {
+            let val = &x - &y;
+            let (neg, pos) = val.split();
+
+            assert!(neg.geq(&Value::new()));
+            assert!(pos.geq(&Value::new()));
+            assert_eq!(&pos - &neg, val.normalize());
+          }
+        }
+    }
+
+    mod partition {
+        use plutus_ledger_api::generators::correct::v1::arb_value;
+        use plutus_ledger_api::v1::value::Value;
+        use proptest::prelude::*;
+
+        proptest! {
+          #[test]
+          fn test_partition_merge_roundtrip(val in arb_value(), max_value_size in 100..1000usize, max_assets in 1..10usize) {
+            let parts = val.partition(max_value_size, max_assets)?;
+
+            prop_assert_eq!(Value::merge(&parts), val);
+          }
+
+          #[test]
+          fn test_partition_limits(val in arb_value(), max_value_size in 100..1000usize, max_assets in 1..10usize) {
+            for part in val.partition(max_value_size, max_assets)? {
+              prop_assert!(part.serialized_size() <= max_value_size);
+              prop_assert!(part.flatten().iter().filter(|(cs, _, _)| !cs.is_ada()).count() <= max_assets);
+            }
+          }
+        }
+    }
+
+    mod fingerprint {
+        use std::str::FromStr;
+
+        use plutus_ledger_api::generators::correct::v1::arb_asset_class;
+        use plutus_ledger_api::v1::value::{AssetClass, AssetFingerprint};
+        use proptest::prelude::*;
+
+        /// Test vectors from CIP-14
+        #[test]
+        fn test_cip14_vectors() {
+            let vectors = [
+                (
+                    "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                    "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3",
+                ),
+                (
+                    "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc37e",
+                    "asset1nl0puwxmhas8fawxp8nx4e2q3wekg969n2auw3",
+                ),
+                (
+                    "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                    "asset1uyuxku60yqe57nusqzjx38aan3f2wq6s93f6ea",
+                ),
+                (
+                    "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373.504154415445",
+                    "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92",
+                ),
+                (
+                    "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209.504154415445",
+                    "asset1hv4p5tv2a837mzqrst04d0dcptdjmluqvdx9k3",
+                ),
+            ];
+
+            for (asset_class, fingerprint) in vectors {
+                let asset_class = AssetClass::from_str(asset_class).unwrap();
+
+                assert_eq!(asset_class.fingerprint().to_string(), fingerprint);
+                assert_eq!(
+                    AssetFingerprint::from_str(fingerprint).unwrap(),
+                    asset_class.fingerprint()
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid_prefix() {
+            assert!(
+                AssetFingerprint::from_str("addr1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3").is_err()
+            );
+        }
+
+        proptest! {
+          #[test]
+          fn test_fingerprint_roundtrip(asset_class in arb_asset_class()) {
+            let fingerprint = asset_class.fingerprint();
+
+            assert_eq!(AssetFingerprint::from_str(&fingerprint.to_string())?, fingerprint);
+          }
+        }
+    }
+
+    mod cip67 {
+        use std::str::FromStr;
+
+        use plutus_ledger_api::v1::value::{Cip67LabelError, TokenName};
+        use proptest::{collection::vec, prelude::*};
+
+        #[test]
+        fn test_cip68_labels() {
+            let body = b"Token";
+
+            for (token_name, prefix) in [
+                (TokenName::cip68_reference_nft(body), "000643b0"),
+                (TokenName::cip68_nft(body), "000de140"),
+                (TokenName::cip68_ft(body), "0014df10"),
+                (TokenName::cip68_rft(body), "001bc280"),
+            ] {
+                assert_eq!(
+                    token_name.unwrap(),
+                    TokenName::from_str(&format!("{}546f6b656e", prefix)).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid_labels() {
+            let parse = |s| TokenName::from_str(s).unwrap();
+
+            assert_eq!(parse("000de1").label(), Err(Cip67LabelError::MissingLabel));
+            assert_eq!(
+                parse("100de140").label(),
+                Err(Cip67LabelError::InvalidBrackets)
+            );
+            assert_eq!(
+                parse("000de150").label(),
+                Err(Cip67LabelError::InvalidChecksum {
+                    label: 222,
+                    expected: 0x14,
+                    got: 0x15
+                })
+            );
+        }
+
+        #[test]
+        fn test_body_too_long() {
+            assert!(TokenName::cip68_nft(&[0; 29]).is_err());
+        }
+
+        proptest! {
+          #[test]
+          fn test_label_roundtrip(label in any::<u16>(), body in vec(any::<u8>(), 0..=28)) {
+            let token_name = TokenName::with_label(label, &body)?;
+
+            prop_assert_eq!(token_name.label(), Ok((label, body.as_slice())));
+          }
+        }
+    }
+
+    mod cli {
+        use std::str::FromStr;
+
+        use num_bigint::BigInt;
+        use plutus_ledger_api::generators::correct::v1::{arb_address, arb_value};
+        use plutus_ledger_api::network::Network;
+        use plutus_ledger_api::v1::address::Address;
+        use plutus_ledger_api::v1::transaction::parse_cli_tx_out;
+        use plutus_ledger_api::v1::value::{CurrencySymbol, TokenName, Value};
+        use proptest::prelude::*;
+
+        const POLICY: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373";
+
+        /// Render a value in cardano-cli syntax
+        fn to_cli_str(value: &Value) -> String {
+            value
+                .flatten()
+                .into_iter()
+                .map(|(cs, tn, amount)| {
+                    if cs.is_ada() {
+                        format!("{} lovelace", amount)
+                    } else {
+                        format!("{} {}.{}", amount, cs, tn)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" + ")
+        }
+
+        #[test]
+        fn test_cli_value() {
+            let currency_symbol = CurrencySymbol::from_str(POLICY).unwrap();
+            let token_name = TokenName::from_str("504154415445").unwrap();
+            let expected = Value::ada_value(&BigInt::from(2000000))
+                + Value::token_value(&currency_symbol, &token_name, &BigInt::from(5))
+                + Value::token_value(&currency_symbol, &TokenName::ada(), &BigInt::from(-1));
+
+            for s in [
+                format!("2000000 lovelace + 5 {POLICY}.504154415445 + -1 {POLICY}"),
+                format!("2000000+5 {POLICY}.504154415445+-1 {POLICY}"),
+                format!("\"1000000 + 5 {POLICY}.504154415445 + 1000000 + -1 {POLICY}\""),
+            ] {
+                assert_eq!(Value::from_cli_str(&s).unwrap(), expected);
+            }
+        }
+
+        #[test]
+        fn test_invalid_cli_value() {
+            assert!(Value::from_cli_str("").is_err());
+            assert!(Value::from_cli_str("5 ada").is_err());
+            assert!(Value::from_cli_str("\"5 lovelace").is_err());
+            assert!(Value::from_cli_str(&format!("5 {POLICY}.MyNFT")).is_err());
+        }
+
+        #[test]
+        fn test_cli_tx_out_with_spaces() {
+            let address = "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz";
+            let currency_symbol = CurrencySymbol::from_str(POLICY).unwrap();
+            let token_name = TokenName::from_str("504154415445").unwrap();
+            let expected = (
+                Address::from_str(address).unwrap(),
+                Value::ada_value(&BigInt::from(1000000))
+                    + Value::token_value(&currency_symbol, &token_name, &BigInt::from(5)),
+            );
+
+            for s in [
+                format!("{address} + 1000000 + 5 {POLICY}.504154415445"),
+                format!("{address}  +1000000 + 5 {POLICY}.504154415445"),
+                format!("\"{address}\t+ 1000000 + 5 {POLICY}.504154415445\""),
+            ] {
+                assert_eq!(parse_cli_tx_out(&s).unwrap(), expected);
+            }
+        }
+
+        #[test]
+        fn test_invalid_cli_tx_out() {
+            assert!(parse_cli_tx_out("2000000 lovelace").is_err());
+            assert!(parse_cli_tx_out("addr_test1xyz+2000000").is_err());
+            assert!(parse_cli_tx_out("addr_test1xyz + 2000000").is_err());
+        }
+
+        proptest! {
+          #[test]
+          fn test_cli_value_roundtrip(value in arb_value()) {
+            prop_assume!(!value.is_empty());
+
+            prop_assert_eq!(Value::from_cli_str(&to_cli_str(&value))?, value);
+          }
+
+          #[test]
+          fn test_cli_tx_out_roundtrip(address in arb_address(), value in arb_value()) {
+            prop_assume!(!value.is_empty());
+
+            let bech32 = address.with_extra_info(&Network::preprod()).to_string();
+
+            prop_assert_eq!(
+                parse_cli_tx_out(&format!("{}+{}", bech32, to_cli_str(&value)))?,
+                (address.clone(), value.clone())
+            );
+            prop_assert_eq!(
+                parse_cli_tx_out(&format!("\"{}+{}\"", bech32, to_cli_str(&value)))?,
+                (address.clone(), value.clone())
+            );
+            prop_assert_eq!(
+                parse_cli_tx_out(&format!("{}+\"{}\"", bech32, to_cli_str(&value)))?,
+                (address.clone(), value.clone())
+            );
+            prop_assert_eq!(
+                parse_cli_tx_out(&format!("{} + {}", bech32, to_cli_str(&value)))?,
+                (address, value)
+            );
+          }
         }
     }
 }